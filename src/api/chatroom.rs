@@ -1,12 +1,296 @@
 use crate::core::RongCloud;
 use crate::core::RongCloudError;
+use crate::messages::Message;
 use crate::types::RcResponse;
-use serde::Serialize;
+use crate::util::deserialize_embedded_json;
+use serde::Deserialize;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::collections::HashMap;
 
-#[derive(Debug, Serialize)]
-pub struct ChatroomModel<'a> {
-    pub id: &'a str,
-    pub name: &'a str,
+/// 聊天室销毁类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum ChatroomDestroyType {
+    /// 不活跃时自动销毁（默认）
+    #[default]
+    Inactive = 0,
+    /// 到达指定时长后销毁
+    Fixed = 1,
+}
+
+/// 聊天室创建选项
+#[derive(Debug, Clone, Default)]
+pub struct ChatroomCreateOptions {
+    /// 销毁类型
+    pub destroy_type: ChatroomDestroyType,
+    /// 销毁时长（分钟），仅 `ChatroomDestroyType::Fixed` 时生效
+    pub destroy_time: Option<i32>,
+    /// 是否全体禁言
+    pub mute_all: bool,
+    /// 禁言白名单用户
+    pub whitelist_user_ids: Vec<String>,
+}
+
+impl ChatroomCreateOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 聊天室在创建 `minutes` 分钟后销毁
+    pub fn destroy_after(mut self, minutes: i32) -> Self {
+        self.destroy_type = ChatroomDestroyType::Fixed;
+        self.destroy_time = Some(minutes);
+        self
+    }
+
+    pub fn mute_all(mut self, mute_all: bool) -> Self {
+        self.mute_all = mute_all;
+        self
+    }
+
+    pub fn whitelist_user(mut self, user_id: impl Into<String>) -> Self {
+        self.whitelist_user_ids.push(user_id.into());
+        self
+    }
+}
+
+/// 聊天室信息
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChatroomInfo {
+    /// 聊天室 ID
+    #[serde(rename = "chatroomId")]
+    pub chatroom_id: String,
+    /// 创建时间（毫秒）
+    #[serde(rename = "createTime", default)]
+    pub create_time: Option<i64>,
+    /// 当前成员数
+    #[serde(rename = "memberCount", default)]
+    pub member_count: Option<i32>,
+    /// 销毁类型
+    #[serde(rename = "destroyType", default)]
+    pub destroy_type: ChatroomDestroyType,
+    /// 销毁时长（分钟）
+    #[serde(rename = "destroyTime", default)]
+    pub destroy_time: Option<i32>,
+    /// 是否全体禁言
+    #[serde(rename = "ban", default)]
+    pub mute_all: bool,
+}
+
+/// 聊天室信息（旧版查询接口）
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChatroomBrief {
+    /// 聊天室 ID
+    #[serde(rename = "chrmId")]
+    pub chatroom_id: String,
+    /// 聊天室名称
+    #[serde(default)]
+    pub name: Option<String>,
+    /// 创建时间，格式为 `yyyy-MM-dd HH:mm:ss`
+    #[serde(default)]
+    pub time: Option<String>,
+}
+
+/// 聊天室信息查询结果（旧版查询接口）
+#[derive(Debug, Deserialize)]
+pub struct ChatroomBriefList {
+    /// 聊天室列表
+    #[serde(rename = "chatRooms", default)]
+    pub chatrooms: Vec<ChatroomBrief>,
+}
+
+/// 聊天室成员
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChatroomUser {
+    /// 用户 ID
+    pub id: String,
+    /// 加入时间，格式为 `yyyy-MM-dd HH:mm:ss`
+    #[serde(default)]
+    pub time: Option<String>,
+}
+
+/// 聊天室成员查询结果
+#[derive(Debug, Deserialize)]
+pub struct ChatroomUserList {
+    /// 聊天室总人数
+    #[serde(default)]
+    pub total: i64,
+    /// 成员列表
+    #[serde(default)]
+    pub users: Vec<ChatroomUser>,
+}
+
+/// 用户是否在聊天室中
+#[derive(Debug, Deserialize)]
+pub struct ChatroomUserExist {
+    /// 是否在聊天室中
+    #[serde(rename = "isInChrm", default)]
+    pub is_in_chatroom: bool,
+}
+
+/// 保活聊天室列表
+#[derive(Debug, Deserialize)]
+pub struct ChatroomKeepaliveList {
+    /// 聊天室 ID 列表
+    #[serde(rename = "chatrooms", default)]
+    pub chatroom_ids: Vec<String>,
+}
+
+/// 低优先级消息类型列表
+#[derive(Debug, Deserialize)]
+pub struct ChatroomMessagePriorityList {
+    /// 消息类型列表
+    #[serde(rename = "objectNames", default)]
+    pub object_names: Vec<String>,
+}
+
+/// 聊天室属性设置/删除选项
+#[derive(Debug, Clone, Default)]
+pub struct ChatroomEntryOptions {
+    /// 用户退出聊天室后是否自动删除其设置的属性
+    pub auto_delete: bool,
+    /// 属性已存在时是否覆盖（仅对其他用户设置的属性生效）
+    pub overwrite: bool,
+    /// 属性变更时发送的通知消息类型
+    pub object_name: Option<String>,
+    /// 属性变更时发送的通知消息内容
+    pub content: Option<String>,
+}
+
+impl ChatroomEntryOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn auto_delete(mut self, auto_delete: bool) -> Self {
+        self.auto_delete = auto_delete;
+        self
+    }
+
+    pub fn overwrite(mut self, overwrite: bool) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
+
+    /// 设置属性变更时在聊天室内发送的通知消息
+    pub fn notification<M: Message>(mut self, message: &M) -> Result<Self, serde_json::Error> {
+        self.object_name = Some(message.message_type().to_string());
+        self.content = Some(message.to_json()?);
+        Ok(self)
+    }
+
+    fn push_notification(&self, params: &mut Vec<(&'static str, String)>) {
+        if let (Some(object_name), Some(content)) = (&self.object_name, &self.content) {
+            params.push(("objectName", object_name.clone()));
+            params.push(("content", content.clone()));
+        }
+    }
+}
+
+/// 聊天室属性
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChatroomEntry {
+    /// 属性名
+    pub key: String,
+    /// 属性值
+    pub value: String,
+    /// 属性所属用户 ID
+    #[serde(rename = "userId", default)]
+    pub user_id: Option<String>,
+    /// 用户退出聊天室后是否自动删除（1 为自动删除）
+    #[serde(rename = "autoDelete", default)]
+    pub auto_delete: i32,
+    /// 最近一次设置时间（毫秒）
+    #[serde(
+        rename = "lastSetTime",
+        default,
+        deserialize_with = "deserialize_embedded_json"
+    )]
+    pub last_set_time: Option<i64>,
+}
+
+impl ChatroomEntry {
+    pub fn is_auto_delete(&self) -> bool {
+        self.auto_delete == 1
+    }
+}
+
+/// 聊天室属性查询结果
+#[derive(Debug, Deserialize)]
+pub struct ChatroomEntryList {
+    /// 属性列表
+    #[serde(default)]
+    pub keys: Vec<ChatroomEntry>,
+}
+
+/// 聊天室受限用户（禁言/封禁）
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChatroomRestrictedUser {
+    /// 用户 ID
+    #[serde(rename = "userId")]
+    pub user_id: String,
+    /// 解除时间，格式为 `yyyy-MM-dd HH:mm:ss`
+    #[serde(rename = "time", default)]
+    pub expire_time: Option<String>,
+}
+
+/// 聊天室受限用户列表
+#[derive(Debug, Deserialize)]
+pub struct ChatroomRestrictedUserList {
+    /// 用户列表
+    #[serde(default)]
+    pub users: Vec<ChatroomRestrictedUser>,
+}
+
+/// 全体禁言的聊天室列表
+#[derive(Debug, Deserialize)]
+pub struct ChatroomBannedList {
+    /// 聊天室 ID 列表
+    #[serde(rename = "chatroomIds", default)]
+    pub chatroom_ids: Vec<String>,
+}
+
+/// 聊天室全体禁言状态
+#[derive(Debug, Deserialize)]
+pub struct ChatroomBanStatus {
+    /// 1 为已全体禁言，0 为未禁言
+    #[serde(default)]
+    pub status: i32,
+}
+
+impl ChatroomBanStatus {
+    pub fn is_banned(&self) -> bool {
+        self.status == 1
+    }
+}
+
+/// 聊天室白名单用户列表
+#[derive(Debug, Deserialize)]
+pub struct ChatroomWhitelistUsers {
+    /// 用户 ID 列表
+    #[serde(alias = "users", rename = "userIds", default)]
+    pub user_ids: Vec<String>,
+}
+
+/// 聊天室消息白名单
+#[derive(Debug, Deserialize)]
+pub struct ChatroomMessageWhitelist {
+    /// 消息类型列表
+    #[serde(rename = "whitlistMsgType", alias = "whitelistMsgType", default)]
+    pub object_names: Vec<String>,
+}
+
+/// 构造聊天室 ID 与多个用户 ID 的表单参数
+fn chatroom_user_params(
+    chatroom_id: Option<&str>,
+    user_ids: &[&str],
+) -> Vec<(&'static str, String)> {
+    let mut params = Vec::with_capacity(user_ids.len() + 1);
+    if let Some(chatroom_id) = chatroom_id {
+        params.push(("chatroomId", chatroom_id.to_string()));
+    }
+    params.extend(user_ids.iter().map(|id| ("userId", id.to_string())));
+    params
 }
 
 impl RongCloud {
@@ -15,14 +299,19 @@ impl RongCloud {
     /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/create
     pub async fn chatroom_create(
         &self,
-        chatrooms: Vec<ChatroomModel<'_>>,
+        chatroom_id: &str,
+        options: &ChatroomCreateOptions,
     ) -> Result<RcResponse<()>, RongCloudError> {
-        let mut params: Vec<(String, String)> = Vec::new();
-        for chatroom in chatrooms {
-            params.push((
-                format!("chatroom[{}]", chatroom.id),
-                chatroom.name.to_string(),
-            ));
+        let mut params = vec![
+            ("chatroomId", chatroom_id.to_string()),
+            ("destroyType", (options.destroy_type as u8).to_string()),
+            ("isBan", options.mute_all.to_string()),
+        ];
+        if let Some(destroy_time) = options.destroy_time {
+            params.push(("destroyTime", destroy_time.to_string()));
+        }
+        for user_id in &options.whitelist_user_ids {
+            params.push(("whiteUserIds", user_id.clone()));
         }
         self.post(
             super::endpoints::CHATROOM_CREATE,
@@ -54,7 +343,7 @@ impl RongCloud {
     pub async fn chatroom_query(
         &self,
         chatroom_id: &str,
-    ) -> Result<RcResponse<()>, RongCloudError> {
+    ) -> Result<RcResponse<ChatroomBriefList>, RongCloudError> {
         let params = vec![("chatroomId", chatroom_id)];
         self.post(
             super::endpoints::CHATROOM_QUERY,
@@ -72,7 +361,7 @@ impl RongCloud {
         chatroom_id: &str,
         count: i32,
         order: i32,
-    ) -> Result<RcResponse<()>, RongCloudError> {
+    ) -> Result<RcResponse<ChatroomUserList>, RongCloudError> {
         let params = vec![
             ("chatroomId", chatroom_id.to_string()),
             ("count", count.to_string()),
@@ -93,7 +382,7 @@ impl RongCloud {
         &self,
         chatroom_id: &str,
         user_id: &str,
-    ) -> Result<RcResponse<()>, RongCloudError> {
+    ) -> Result<RcResponse<ChatroomUserExist>, RongCloudError> {
         let params = vec![("chatroomId", chatroom_id), ("userId", user_id)];
         self.post(
             super::endpoints::CHATROOM_USER_EXIST,
@@ -102,33 +391,830 @@ impl RongCloud {
         )
         .await
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::core::RongCloudConfig;
-    use mockito;
+    /// Get chatroom info including its destroy policy and mute-all state.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/get
+    pub async fn chatroom_get(
+        &self,
+        chatroom_id: &str,
+    ) -> Result<RcResponse<ChatroomInfo>, RongCloudError> {
+        let params = vec![("chatroomId", chatroom_id)];
+        self.post(
+            super::endpoints::CHATROOM_GET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
 
-    #[tokio::test]
-    async fn test_chatroom_ops() {
-        let mut server = mockito::Server::new_async().await;
-        let url = server.url();
-        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
-        let client = RongCloud::new(config);
+    /// Set the destroy policy of a chatroom.
+    ///
+    /// `destroy_time` is in minutes and only used with `ChatroomDestroyType::Fixed`.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/destroy-set
+    pub async fn chatroom_destroy_set(
+        &self,
+        chatroom_id: &str,
+        destroy_type: ChatroomDestroyType,
+        destroy_time: Option<i32>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let mut params = vec![
+            ("chatroomId", chatroom_id.to_string()),
+            ("destroyType", (destroy_type as u8).to_string()),
+        ];
+        if let Some(destroy_time) = destroy_time {
+            params.push(("destroyTime", destroy_time.to_string()));
+        }
+        self.post(
+            super::endpoints::CHATROOM_DESTROY_SET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
 
-        let mock_create = server
-            .mock("POST", "/chatroom/create_new.json")
-            .with_status(200)
-            .with_body(r#"{"code": 200}"#)
-            .create_async()
-            .await;
+    /// Bind a chatroom to an RTC room, so it is destroyed together with the RTC room.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/correlation-rtc
+    pub async fn chatroom_correlation_rtc(
+        &self,
+        chatroom_id: &str,
+        rtc_room_id: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![("chatroomId", chatroom_id), ("rtcRoomId", rtc_room_id)];
+        self.post(
+            super::endpoints::CHATROOM_CORRELATION_RTC,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
 
-        let chatrooms = vec![ChatroomModel {
-            id: "c1",
-            name: "room1",
-        }];
-        let _ = client.chatroom_create(chatrooms).await;
-        mock_create.assert_async().await;
+    // ========================================================================
+    // 聊天室保活
+    // ========================================================================
+
+    /// Keep a chatroom alive so it is never destroyed automatically.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/keepalive/add
+    pub async fn chatroom_keepalive_add(
+        &self,
+        chatroom_id: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![("chatroomId", chatroom_id)];
+        self.post(
+            super::endpoints::CHATROOM_KEEPALIVE_ADD,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Remove a chatroom from the keepalive list.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/keepalive/remove
+    pub async fn chatroom_keepalive_remove(
+        &self,
+        chatroom_id: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![("chatroomId", chatroom_id)];
+        self.post(
+            super::endpoints::CHATROOM_KEEPALIVE_REMOVE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query keepalive chatrooms.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/keepalive/query
+    pub async fn chatroom_keepalive_query(
+        &self,
+    ) -> Result<RcResponse<ChatroomKeepaliveList>, RongCloudError> {
+        let params: Vec<(&str, &str)> = Vec::new();
+        self.post(
+            super::endpoints::CHATROOM_KEEPALIVE_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    // ========================================================================
+    // 聊天室消息优先级
+    // ========================================================================
+
+    /// Mark message types as low priority in chatrooms.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/priority/add
+    pub async fn chatroom_message_priority_add(
+        &self,
+        object_names: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params: Vec<(&str, &str)> = object_names
+            .into_iter()
+            .map(|name| ("objectName", name))
+            .collect();
+        self.post(
+            super::endpoints::CHATROOM_MESSAGE_PRIORITY_ADD,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Remove message types from the low priority list.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/priority/remove
+    pub async fn chatroom_message_priority_remove(
+        &self,
+        object_names: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params: Vec<(&str, &str)> = object_names
+            .into_iter()
+            .map(|name| ("objectName", name))
+            .collect();
+        self.post(
+            super::endpoints::CHATROOM_MESSAGE_PRIORITY_REMOVE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query low priority message types.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/priority/query
+    pub async fn chatroom_message_priority_query(
+        &self,
+    ) -> Result<RcResponse<ChatroomMessagePriorityList>, RongCloudError> {
+        let params: Vec<(&str, &str)> = Vec::new();
+        self.post(
+            super::endpoints::CHATROOM_MESSAGE_PRIORITY_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    // ========================================================================
+    // 聊天室属性
+    // ========================================================================
+
+    /// Set a chatroom attribute.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/entry/set
+    pub async fn chatroom_entry_set(
+        &self,
+        chatroom_id: &str,
+        user_id: &str,
+        key: &str,
+        value: &str,
+        options: &ChatroomEntryOptions,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let mut params = vec![
+            ("chatroomId", chatroom_id.to_string()),
+            ("userId", user_id.to_string()),
+            ("key", key.to_string()),
+            ("value", value.to_string()),
+            ("autoDelete", (options.auto_delete as i32).to_string()),
+            ("overwrite", (options.overwrite as i32).to_string()),
+        ];
+        options.push_notification(&mut params);
+        self.post(
+            super::endpoints::CHATROOM_ENTRY_SET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Batch set chatroom attributes owned by `owner_id`.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/entry/batch-set
+    pub async fn chatroom_entry_batch_set(
+        &self,
+        chatroom_id: &str,
+        owner_id: &str,
+        entries: &HashMap<String, String>,
+        options: &ChatroomEntryOptions,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let mut params = vec![
+            ("chatroomId", chatroom_id.to_string()),
+            ("entryOwnerId", owner_id.to_string()),
+            ("entryInfo", serde_json::to_string(entries)?),
+            ("autoDelete", (options.auto_delete as i32).to_string()),
+            ("overwrite", (options.overwrite as i32).to_string()),
+        ];
+        options.push_notification(&mut params);
+        self.post(
+            super::endpoints::CHATROOM_ENTRY_BATCH_SET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Remove a chatroom attribute.
+    ///
+    /// Only the notification message of `options` is used.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/entry/remove
+    pub async fn chatroom_entry_remove(
+        &self,
+        chatroom_id: &str,
+        user_id: &str,
+        key: &str,
+        options: &ChatroomEntryOptions,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let mut params = vec![
+            ("chatroomId", chatroom_id.to_string()),
+            ("userId", user_id.to_string()),
+            ("key", key.to_string()),
+        ];
+        options.push_notification(&mut params);
+        self.post(
+            super::endpoints::CHATROOM_ENTRY_REMOVE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Batch remove chatroom attributes owned by `owner_id`.
+    ///
+    /// Only the notification message of `options` is used.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/entry/batch-remove
+    pub async fn chatroom_entry_batch_remove(
+        &self,
+        chatroom_id: &str,
+        owner_id: &str,
+        keys: Vec<&str>,
+        options: &ChatroomEntryOptions,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let mut params = vec![
+            ("chatroomId", chatroom_id.to_string()),
+            ("entryOwnerId", owner_id.to_string()),
+            ("entryKeys", serde_json::to_string(&keys)?),
+        ];
+        options.push_notification(&mut params);
+        self.post(
+            super::endpoints::CHATROOM_ENTRY_BATCH_REMOVE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query chatroom attributes.
+    ///
+    /// An empty `keys` returns all attributes of the chatroom.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/entry/query
+    pub async fn chatroom_entry_query(
+        &self,
+        chatroom_id: &str,
+        keys: Vec<&str>,
+    ) -> Result<RcResponse<ChatroomEntryList>, RongCloudError> {
+        let mut params = vec![("chatroomId", chatroom_id)];
+        params.extend(keys.into_iter().map(|key| ("keys", key)));
+        self.post(
+            super::endpoints::CHATROOM_ENTRY_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    // ========================================================================
+    // 聊天室禁言
+    // ========================================================================
+
+    /// Mute users in a chatroom for `minute` minutes.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/gag/add
+    pub async fn chatroom_user_gag_add(
+        &self,
+        chatroom_id: &str,
+        user_ids: Vec<&str>,
+        minute: i32,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let mut params = chatroom_user_params(Some(chatroom_id), &user_ids);
+        params.push(("minute", minute.to_string()));
+        self.post(
+            super::endpoints::CHATROOM_USER_GAG_ADD,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Unmute users in a chatroom.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/gag/rollback
+    pub async fn chatroom_user_gag_rollback(
+        &self,
+        chatroom_id: &str,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = chatroom_user_params(Some(chatroom_id), &user_ids);
+        self.post(
+            super::endpoints::CHATROOM_USER_GAG_ROLLBACK,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query muted users of a chatroom.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/gag/list
+    pub async fn chatroom_user_gag_list(
+        &self,
+        chatroom_id: &str,
+    ) -> Result<RcResponse<ChatroomRestrictedUserList>, RongCloudError> {
+        let params = vec![("chatroomId", chatroom_id)];
+        self.post(
+            super::endpoints::CHATROOM_USER_GAG_LIST,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Mute all members of a chatroom.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/ban/add
+    pub async fn chatroom_ban_add(
+        &self,
+        chatroom_id: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![("chatroomId", chatroom_id)];
+        self.post(
+            super::endpoints::CHATROOM_BAN_ADD,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Cancel muting all members of a chatroom.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/ban/rollback
+    pub async fn chatroom_ban_rollback(
+        &self,
+        chatroom_id: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![("chatroomId", chatroom_id)];
+        self.post(
+            super::endpoints::CHATROOM_BAN_ROLLBACK,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query chatrooms with all members muted.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/ban/query
+    pub async fn chatroom_ban_query(
+        &self,
+        page: i32,
+        size: i32,
+    ) -> Result<RcResponse<ChatroomBannedList>, RongCloudError> {
+        let params = vec![("page", page.to_string()), ("size", size.to_string())];
+        self.post(
+            super::endpoints::CHATROOM_BAN_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Check whether all members of a chatroom are muted.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/ban/check
+    pub async fn chatroom_ban_check(
+        &self,
+        chatroom_id: &str,
+    ) -> Result<RcResponse<ChatroomBanStatus>, RongCloudError> {
+        let params = vec![("chatroomId", chatroom_id)];
+        self.post(
+            super::endpoints::CHATROOM_BAN_CHECK,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Add users to the mute whitelist of a chatroom.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/ban/whitelist/add
+    pub async fn chatroom_user_ban_whitelist_add(
+        &self,
+        chatroom_id: &str,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = chatroom_user_params(Some(chatroom_id), &user_ids);
+        self.post(
+            super::endpoints::CHATROOM_USER_BAN_WHITELIST_ADD,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Remove users from the mute whitelist of a chatroom.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/ban/whitelist/rollback
+    pub async fn chatroom_user_ban_whitelist_rollback(
+        &self,
+        chatroom_id: &str,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = chatroom_user_params(Some(chatroom_id), &user_ids);
+        self.post(
+            super::endpoints::CHATROOM_USER_BAN_WHITELIST_ROLLBACK,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query the mute whitelist of a chatroom.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/ban/whitelist/query
+    pub async fn chatroom_user_ban_whitelist_query(
+        &self,
+        chatroom_id: &str,
+    ) -> Result<RcResponse<ChatroomWhitelistUsers>, RongCloudError> {
+        let params = vec![("chatroomId", chatroom_id)];
+        self.post(
+            super::endpoints::CHATROOM_USER_BAN_WHITELIST_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Mute users in all chatrooms for `minute` minutes.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/global-gag/add
+    pub async fn chatroom_user_ban_add(
+        &self,
+        user_ids: Vec<&str>,
+        minute: i32,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let mut params = chatroom_user_params(None, &user_ids);
+        params.push(("minute", minute.to_string()));
+        self.post(
+            super::endpoints::CHATROOM_USER_BAN_ADD,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Unmute users in all chatrooms.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/global-gag/remove
+    pub async fn chatroom_user_ban_remove(
+        &self,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = chatroom_user_params(None, &user_ids);
+        self.post(
+            super::endpoints::CHATROOM_USER_BAN_REMOVE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query users muted in all chatrooms.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/global-gag/query
+    pub async fn chatroom_user_ban_query(
+        &self,
+    ) -> Result<RcResponse<ChatroomRestrictedUserList>, RongCloudError> {
+        let params: Vec<(&str, &str)> = Vec::new();
+        self.post(
+            super::endpoints::CHATROOM_USER_BAN_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    // ========================================================================
+    // 聊天室封禁
+    // ========================================================================
+
+    /// Block users from a chatroom for `minute` minutes.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/block/add
+    pub async fn chatroom_user_block_add(
+        &self,
+        chatroom_id: &str,
+        user_ids: Vec<&str>,
+        minute: i32,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let mut params = chatroom_user_params(Some(chatroom_id), &user_ids);
+        params.push(("minute", minute.to_string()));
+        self.post(
+            super::endpoints::CHATROOM_USER_BLOCK_ADD,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Unblock users of a chatroom.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/block/rollback
+    pub async fn chatroom_user_block_rollback(
+        &self,
+        chatroom_id: &str,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = chatroom_user_params(Some(chatroom_id), &user_ids);
+        self.post(
+            super::endpoints::CHATROOM_USER_BLOCK_ROLLBACK,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query blocked users of a chatroom.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/block/list
+    pub async fn chatroom_user_block_list(
+        &self,
+        chatroom_id: &str,
+    ) -> Result<RcResponse<ChatroomRestrictedUserList>, RongCloudError> {
+        let params = vec![("chatroomId", chatroom_id)];
+        self.post(
+            super::endpoints::CHATROOM_USER_BLOCK_LIST,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    // ========================================================================
+    // 聊天室白名单
+    // ========================================================================
+
+    /// Add users to the whitelist of a chatroom.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/whitelist/user/add
+    pub async fn chatroom_user_whitelist_add(
+        &self,
+        chatroom_id: &str,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = chatroom_user_params(Some(chatroom_id), &user_ids);
+        self.post(
+            super::endpoints::CHATROOM_USER_WHITELIST_ADD,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Remove users from the whitelist of a chatroom.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/whitelist/user/remove
+    pub async fn chatroom_user_whitelist_remove(
+        &self,
+        chatroom_id: &str,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = chatroom_user_params(Some(chatroom_id), &user_ids);
+        self.post(
+            super::endpoints::CHATROOM_USER_WHITELIST_REMOVE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query the whitelist users of a chatroom.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/whitelist/user/query
+    pub async fn chatroom_user_whitelist_query(
+        &self,
+        chatroom_id: &str,
+    ) -> Result<RcResponse<ChatroomWhitelistUsers>, RongCloudError> {
+        let params = vec![("chatroomId", chatroom_id)];
+        self.post(
+            super::endpoints::CHATROOM_USER_WHITELIST_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Add message types to the chatroom message whitelist.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/whitelist/message/add
+    pub async fn chatroom_whitelist_add(
+        &self,
+        object_names: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params: Vec<(&str, &str)> = object_names
+            .into_iter()
+            .map(|name| ("objectnames", name))
+            .collect();
+        self.post(
+            super::endpoints::CHATROOM_WHITELIST_ADD,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Remove message types from the chatroom message whitelist.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/whitelist/message/delete
+    pub async fn chatroom_whitelist_delete(
+        &self,
+        object_names: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params: Vec<(&str, &str)> = object_names
+            .into_iter()
+            .map(|name| ("objectnames", name))
+            .collect();
+        self.post(
+            super::endpoints::CHATROOM_WHITELIST_DELETE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query the chatroom message whitelist.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/chatroom/whitelist/message/query
+    pub async fn chatroom_whitelist_query(
+        &self,
+    ) -> Result<RcResponse<ChatroomMessageWhitelist>, RongCloudError> {
+        let params: Vec<(&str, &str)> = Vec::new();
+        self.post(
+            super::endpoints::CHATROOM_WHITELIST_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::RongCloudConfig;
+    use mockito;
+
+    #[tokio::test]
+    async fn test_chatroom_ops() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_create = server
+            .mock("POST", "/chatroom/create_new.json")
+            .match_body("chatroomId=c1&destroyType=1&isBan=true&destroyTime=60&whiteUserIds=u1")
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+
+        let options = ChatroomCreateOptions::new()
+            .destroy_after(60)
+            .mute_all(true)
+            .whitelist_user("u1");
+        let result = client.chatroom_create("c1", &options).await;
+        mock_create.assert_async().await;
+        assert!(result.unwrap().is_success());
+
+        let mock_get = server
+            .mock("POST", "/chatroom/get.json")
+            .with_status(200)
+            .with_body(
+                r#"{"code": 200, "chatroomId": "c1", "createTime": 1600000000000, "memberCount": 3, "destroyType": 1, "destroyTime": 60, "ban": true}"#,
+            )
+            .create_async()
+            .await;
+
+        let info = client.chatroom_get("c1").await.unwrap().data.unwrap();
+        mock_get.assert_async().await;
+        assert_eq!(info.destroy_type, ChatroomDestroyType::Fixed);
+        assert_eq!(info.destroy_time, Some(60));
+        assert!(info.mute_all);
+    }
+
+    #[tokio::test]
+    async fn test_chatroom_entry_ops() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_set = server
+            .mock("POST", "/chatroom/entry/batch/set.json")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("entryOwnerId".into(), "u1".into()),
+                mockito::Matcher::UrlEncoded("entryInfo".into(), r#"{"seat1":"u2"}"#.into()),
+                mockito::Matcher::UrlEncoded("autoDelete".into(), "1".into()),
+                mockito::Matcher::UrlEncoded("objectName".into(), "RC:TxtMsg".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+
+        let entries = HashMap::from([("seat1".to_string(), "u2".to_string())]);
+        let options = ChatroomEntryOptions::new()
+            .auto_delete(true)
+            .notification(&crate::messages::TxtMessage::new("seat changed"))
+            .unwrap();
+        let result = client
+            .chatroom_entry_batch_set("c1", "u1", &entries, &options)
+            .await;
+        mock_set.assert_async().await;
+        assert!(result.unwrap().is_success());
+
+        let mock_query = server
+            .mock("POST", "/chatroom/entry/query.json")
+            .match_body("chatroomId=c1&keys=seat1")
+            .with_status(200)
+            .with_body(
+                r#"{"code": 200, "keys": [{"key": "seat1", "value": "u2", "userId": "u1", "autoDelete": 1, "lastSetTime": "1599707134000"}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = client.chatroom_entry_query("c1", vec!["seat1"]).await;
+        mock_query.assert_async().await;
+        let entries = result.unwrap().data.unwrap().keys;
+        assert_eq!(entries[0].user_id.as_deref(), Some("u1"));
+        assert_eq!(entries[0].last_set_time, Some(1599707134000));
+        assert!(entries[0].is_auto_delete());
+    }
+
+    #[tokio::test]
+    async fn test_chatroom_moderation_ops() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_gag = server
+            .mock("POST", "/chatroom/user/gag/add.json")
+            .match_body("chatroomId=c1&userId=u1&userId=u2&minute=30")
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+
+        let result = client
+            .chatroom_user_gag_add("c1", vec!["u1", "u2"], 30)
+            .await;
+        mock_gag.assert_async().await;
+        assert!(result.unwrap().is_success());
+
+        let mock_list = server
+            .mock("POST", "/chatroom/user/block/list.json")
+            .with_status(200)
+            .with_body(
+                r#"{"code": 200, "users": [{"userId": "u1", "time": "2025-09-25 16:12:38"}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = client.chatroom_user_block_list("c1").await;
+        mock_list.assert_async().await;
+        let users = result.unwrap().data.unwrap().users;
+        assert_eq!(users[0].user_id, "u1");
+        assert_eq!(users[0].expire_time.as_deref(), Some("2025-09-25 16:12:38"));
+
+        let mock_whitelist = server
+            .mock("POST", "/chatroom/whitelist/query.json")
+            .with_status(200)
+            .with_body(r#"{"code": 200, "whitlistMsgType": ["RC:TxtMsg"]}"#)
+            .create_async()
+            .await;
+
+        let result = client.chatroom_whitelist_query().await;
+        mock_whitelist.assert_async().await;
+        assert_eq!(
+            result.unwrap().data.unwrap().object_names,
+            vec!["RC:TxtMsg"]
+        );
     }
 }
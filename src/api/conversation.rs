@@ -0,0 +1,329 @@
+//! 会话管理 API
+//!
+//! 包含会话免打扰、会话置顶及清除历史消息
+
+use crate::core::RongCloud;
+use crate::core::RongCloudError;
+use crate::types::{ConversationType, NotificationLevel, RcResponse};
+use serde::Deserialize;
+
+/// 会话免打扰查询结果
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConversationNotification {
+    /// 免打扰级别
+    #[serde(rename = "unpushLevel", default)]
+    pub level: NotificationLevel,
+}
+
+/// 会话免打扰仅支持单聊、群聊、系统会话和超级群
+fn validate_notification_conversation(
+    conversation_type: ConversationType,
+) -> Result<(), RongCloudError> {
+    match conversation_type {
+        ConversationType::Private
+        | ConversationType::Group
+        | ConversationType::System
+        | ConversationType::UltraGroup => Ok(()),
+        other => Err(RongCloudError::InvalidParameter(format!(
+            "do-not-disturb is not supported for {} conversations",
+            other.description()
+        ))),
+    }
+}
+
+impl RongCloud {
+    /// Set the do-not-disturb level of a conversation for a user.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/conversation/set-do-not-disturb
+    pub async fn conversation_notification_set(
+        &self,
+        user_id: &str,
+        conversation_type: ConversationType,
+        target_id: &str,
+        level: NotificationLevel,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        validate_notification_conversation(conversation_type)?;
+        let params = [
+            ("conversationType", (conversation_type as u8).to_string()),
+            ("requestId", user_id.to_string()),
+            ("targetId", target_id.to_string()),
+            ("unpushLevel", (level as i8).to_string()),
+        ];
+        self.post(
+            super::endpoints::CONVERSATION_NOTIFICATION_SET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Get the do-not-disturb level of a conversation for a user.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/conversation/get-do-not-disturb
+    pub async fn conversation_notification_get(
+        &self,
+        user_id: &str,
+        conversation_type: ConversationType,
+        target_id: &str,
+    ) -> Result<RcResponse<ConversationNotification>, RongCloudError> {
+        validate_notification_conversation(conversation_type)?;
+        let params = [
+            ("conversationType", (conversation_type as u8).to_string()),
+            ("requestId", user_id.to_string()),
+            ("targetId", target_id.to_string()),
+        ];
+        self.post(
+            super::endpoints::CONVERSATION_NOTIFICATION_GET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Set the do-not-disturb level of all conversations of a type for a user.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/conversation/set-type-do-not-disturb
+    pub async fn conversation_type_notification_set(
+        &self,
+        user_id: &str,
+        conversation_type: ConversationType,
+        level: NotificationLevel,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        validate_notification_conversation(conversation_type)?;
+        let params = [
+            ("conversationType", (conversation_type as u8).to_string()),
+            ("requestId", user_id.to_string()),
+            ("unpushLevel", (level as i8).to_string()),
+        ];
+        self.post(
+            super::endpoints::CONVERSATION_TYPE_NOTIFICATION_SET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Get the do-not-disturb level of a conversation type for a user.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/conversation/get-type-do-not-disturb
+    pub async fn conversation_type_notification_get(
+        &self,
+        user_id: &str,
+        conversation_type: ConversationType,
+    ) -> Result<RcResponse<ConversationNotification>, RongCloudError> {
+        validate_notification_conversation(conversation_type)?;
+        let params = [
+            ("conversationType", (conversation_type as u8).to_string()),
+            ("requestId", user_id.to_string()),
+        ];
+        self.post(
+            super::endpoints::CONVERSATION_TYPE_NOTIFICATION_GET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Pin or unpin a conversation for a user.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/conversation/top
+    pub async fn conversation_set_top(
+        &self,
+        user_id: &str,
+        conversation_type: ConversationType,
+        target_id: &str,
+        set_top: bool,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = [
+            ("userId", user_id.to_string()),
+            ("conversationType", (conversation_type as u8).to_string()),
+            ("targetId", target_id.to_string()),
+            ("setTop", set_top.to_string()),
+        ];
+        self.post(
+            super::endpoints::CONVERSATION_TOP_SET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Clear the server-side history of a conversation for a user.
+    ///
+    /// When `msg_timestamp` (milliseconds) is given, only messages sent before
+    /// it are cleared; otherwise the whole history is cleared.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/conversation/clean-history
+    pub async fn conversation_clean_history(
+        &self,
+        user_id: &str,
+        conversation_type: ConversationType,
+        target_id: &str,
+        msg_timestamp: Option<i64>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let mut params = vec![
+            ("fromUserId", user_id.to_string()),
+            ("conversationType", (conversation_type as u8).to_string()),
+            ("targetId", target_id.to_string()),
+        ];
+        if let Some(ts) = msg_timestamp {
+            params.push(("msgTimestamp", ts.to_string()));
+        }
+        self.post(
+            super::endpoints::CONVERSATION_MESSAGE_CLEAN,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::RongCloudConfig;
+    use mockito;
+
+    #[tokio::test]
+    async fn test_conversation_notification() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_set = server
+            .mock("POST", "/conversation/notification/set.json")
+            .match_body("conversationType=3&requestId=u1&targetId=g1&unpushLevel=1")
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+        let mock_get = server
+            .mock("POST", "/conversation/notification/get.json")
+            .match_body("conversationType=3&requestId=u1&targetId=g1")
+            .with_status(200)
+            .with_body(r#"{"code": 200, "unpushLevel": 1}"#)
+            .create_async()
+            .await;
+
+        let result = client
+            .conversation_notification_set(
+                "u1",
+                ConversationType::Group,
+                "g1",
+                NotificationLevel::Mention,
+            )
+            .await;
+        mock_set.assert_async().await;
+        assert!(result.unwrap().is_success());
+
+        let result = client
+            .conversation_notification_get("u1", ConversationType::Group, "g1")
+            .await;
+        mock_get.assert_async().await;
+        assert_eq!(
+            result.unwrap().data.unwrap().level,
+            NotificationLevel::Mention
+        );
+
+        let result = client
+            .conversation_notification_get("u1", ConversationType::ChatRoom, "r1")
+            .await;
+        assert!(matches!(result, Err(RongCloudError::InvalidParameter(_))));
+    }
+
+    #[tokio::test]
+    async fn test_conversation_type_notification() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_set = server
+            .mock("POST", "/conversation/type/notification/set.json")
+            .match_body("conversationType=1&requestId=u1&unpushLevel=-1")
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+        let mock_get = server
+            .mock("POST", "/conversation/type/notification/get.json")
+            .match_body("conversationType=1&requestId=u1")
+            .with_status(200)
+            .with_body(r#"{"code": 200, "unpushLevel": 5}"#)
+            .create_async()
+            .await;
+
+        let result = client
+            .conversation_type_notification_set(
+                "u1",
+                ConversationType::Private,
+                NotificationLevel::All,
+            )
+            .await;
+        mock_set.assert_async().await;
+        assert!(result.unwrap().is_success());
+
+        let result = client
+            .conversation_type_notification_get("u1", ConversationType::Private)
+            .await;
+        mock_get.assert_async().await;
+        assert_eq!(
+            result.unwrap().data.unwrap().level,
+            NotificationLevel::Blocked
+        );
+    }
+
+    #[tokio::test]
+    async fn test_conversation_top_and_clean() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_top = server
+            .mock("POST", "/conversation/top/set.json")
+            .match_body("userId=u1&conversationType=1&targetId=support&setTop=true")
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+        let mock_clean = server
+            .mock("POST", "/conversation/message/history/clean.json")
+            .match_body("fromUserId=u1&conversationType=3&targetId=g1&msgTimestamp=1700000000000")
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+        let mock_clean_all = server
+            .mock("POST", "/conversation/message/history/clean.json")
+            .match_body("fromUserId=u1&conversationType=1&targetId=u2")
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+
+        let result = client
+            .conversation_set_top("u1", ConversationType::Private, "support", true)
+            .await;
+        mock_top.assert_async().await;
+        assert!(result.unwrap().is_success());
+
+        let result = client
+            .conversation_clean_history(
+                "u1",
+                ConversationType::Group,
+                "g1",
+                Some(1_700_000_000_000),
+            )
+            .await;
+        mock_clean.assert_async().await;
+        assert!(result.unwrap().is_success());
+
+        let result = client
+            .conversation_clean_history("u1", ConversationType::Private, "u2", None)
+            .await;
+        mock_clean_all.assert_async().await;
+        assert!(result.unwrap().is_success());
+    }
+}
@@ -194,6 +194,14 @@ pub const STATUS_MESSAGE_GROUP: &str = "/statusmessage/group/publish.json";
 /// 
 /// 频率限制: 100 条/每秒，按目标群组数量计算条数；单个频道限 20 条/每秒，可调频
 pub const MESSAGE_ULTRA_GROUP_PUBLISH: &str = "/message/ultragroup/publish.json";
+/// 发送聊天室消息
+/// 
+/// 频率限制: 100 条/每秒，按目标聊天室数量计算条数，可调频
+pub const MESSAGE_CHATROOM_PUBLISH: &str = "/message/chatroom/publish.json";
+/// 发送全体聊天室广播消息
+/// 
+/// 频率限制: 1 次/每秒，可调频
+pub const MESSAGE_CHATROOM_BROADCAST: &str = "/message/chatroom/broadcast.json";
 /// 设置单群聊消息扩展
 /// 
 /// 频率限制: 100 次/每秒，其中群聊消息扩展最多 20 次，可调频
@@ -440,6 +448,186 @@ pub const GROUP_USER_BAN_WHITELIST_ROLLBACK: &str = "/group/user/ban/whitelist/r
 /// 频率限制: 100 次/每秒，可调频
 pub const GROUP_USER_BAN_WHITELIST_QUERY: &str = "/group/user/ban/whitelist/query.json";
 
+// ============================================================================
+// 超级群管理 API
+// ============================================================================
+
+/// 创建超级群
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_CREATE: &str = "/ultragroup/create.json";
+/// 加入超级群
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_JOIN: &str = "/ultragroup/join.json";
+/// 退出超级群
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_QUIT: &str = "/ultragroup/quit.json";
+/// 解散超级群
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_DISMISS: &str = "/ultragroup/dis.json";
+/// 刷新超级群信息
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_REFRESH: &str = "/ultragroup/refresh.json";
+/// 查询用户是否为群成员
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_MEMBER_EXIST: &str = "/ultragroup/member/exist.json";
+/// 创建频道
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_CHANNEL_CREATE: &str = "/ultragroup/channel/create.json";
+/// 删除频道
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_CHANNEL_DELETE: &str = "/ultragroup/channel/del.json";
+/// 查询频道列表
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_CHANNEL_GET: &str = "/ultragroup/channel/get.json";
+/// 获取指定超级群消息内容
+/// 
+/// 频率限制: 5 次/每秒
+pub const ULTRA_GROUP_MSG_GET: &str = "/ultragroup/msg/get.json";
+/// 修改超级群消息
+/// 
+/// 频率限制: 100 次/每分钟
+pub const ULTRA_GROUP_MSG_MODIFY: &str = "/ultragroup/msg/modify.json";
+/// 搜索超级群消息
+/// 
+/// 频率限制: 100 次/每分钟
+pub const ULTRA_GROUP_HIS_MSG_QUERY: &str = "/ultragroup/hismsg/query.json";
+/// 设置超级群消息扩展
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_MESSAGE_EXPANSION_SET: &str = "/ultragroup/message/expansion/set.json";
+/// 删除超级群消息扩展
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_MESSAGE_EXPANSION_DELETE: &str = "/ultragroup/message/expansion/delete.json";
+/// 获取超级群消息扩展
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_MESSAGE_EXPANSION_QUERY: &str = "/ultragroup/message/expansion/query.json";
+
+// ============================================================================
+// 超级群私有频道 API
+// ============================================================================
+
+/// 变更频道类型
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_CHANNEL_TYPE_CHANGE: &str = "/ultragroup/channel/type/change.json";
+/// 添加私有频道成员
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_CHANNEL_PRIVATE_USERS_ADD: &str = "/ultragroup/channel/private/users/add.json";
+/// 删除私有频道成员
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_CHANNEL_PRIVATE_USERS_DELETE: &str = "/ultragroup/channel/private/users/del.json";
+/// 查询私有频道成员列表
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_CHANNEL_PRIVATE_USERS_GET: &str = "/ultragroup/channel/private/users/get.json";
+/// 查询用户所属的私有频道
+/// 
+/// 频率限制: 100 次/每秒
+pub const ULTRA_GROUP_USER_CHANNEL_QUERY: &str = "/ultragroup/user/channel/query.json";
+
+// ============================================================================
+// 超级群用户组 API
+// ============================================================================
+
+/// 创建用户组
+/// 
+/// 频率限制: 100 次/每秒
+pub const ULTRA_GROUP_USER_GROUP_ADD: &str = "/ultragroup/usergroup/add.json";
+/// 删除用户组
+/// 
+/// 频率限制: 100 次/每秒
+pub const ULTRA_GROUP_USER_GROUP_DELETE: &str = "/ultragroup/usergroup/del.json";
+/// 查询用户组列表
+/// 
+/// 频率限制: 100 次/每秒
+pub const ULTRA_GROUP_USER_GROUP_QUERY: &str = "/ultragroup/usergroup/query.json";
+/// 用户组添加用户
+/// 
+/// 频率限制: 100 次/每秒
+pub const ULTRA_GROUP_USER_GROUP_USER_ADD: &str = "/ultragroup/usergroup/user/add.json";
+/// 用户组移出用户
+/// 
+/// 频率限制: 100 次/每秒
+pub const ULTRA_GROUP_USER_GROUP_USER_DELETE: &str = "/ultragroup/usergroup/user/del.json";
+/// 查询用户所属用户组
+/// 
+/// 频率限制: 100 次/每秒
+pub const ULTRA_GROUP_USER_USER_GROUP_QUERY: &str = "/ultragroup/user/usergroup/query.json";
+/// 绑定频道与用户组
+/// 
+/// 频率限制: 100 次/每秒
+pub const ULTRA_GROUP_CHANNEL_USER_GROUP_BIND: &str = "/ultragroup/channel/usergroup/bind.json";
+/// 解绑频道与用户组
+/// 
+/// 频率限制: 100 次/每秒
+pub const ULTRA_GROUP_CHANNEL_USER_GROUP_UNBIND: &str = "/ultragroup/channel/usergroup/unbind.json";
+/// 查询频道绑定的用户组
+/// 
+/// 频率限制: 100 次/每秒
+pub const ULTRA_GROUP_CHANNEL_USER_GROUP_QUERY: &str = "/ultragroup/channel/usergroup/query.json";
+/// 查询用户组绑定的频道
+/// 
+/// 频率限制: 100 次/每秒
+pub const ULTRA_GROUP_USER_GROUP_CHANNEL_QUERY: &str = "/ultragroup/usergroup/channel/query.json";
+
+// ============================================================================
+// 超级群禁言服务 API
+// ============================================================================
+
+/// 禁言指定超级群成员
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_USER_BANNED_ADD: &str = "/ultragroup/userbanned/add.json";
+/// 取消指定超级群成员禁言
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_USER_BANNED_DELETE: &str = "/ultragroup/userbanned/del.json";
+/// 查询超级群成员禁言列表
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_USER_BANNED_GET: &str = "/ultragroup/userbanned/get.json";
+/// 设置超级群全体禁言
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_GLOBAL_BANNED_SET: &str = "/ultragroup/globalbanned/set.json";
+/// 查询超级群全体禁言
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_GLOBAL_BANNED_GET: &str = "/ultragroup/globalbanned/get.json";
+/// 加入超级群全体禁言白名单
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_BANNED_WHITELIST_ADD: &str = "/ultragroup/banned/whitelist/add.json";
+/// 移出超级群全体禁言白名单
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_BANNED_WHITELIST_DELETE: &str = "/ultragroup/banned/whitelist/del.json";
+/// 查询超级群全体禁言白名单
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_BANNED_WHITELIST_GET: &str = "/ultragroup/banned/whitelist/get.json";
+/// 设置群/频道默认免打扰
+/// 
+/// 频率限制: 100 次/每秒，可调频
+pub const ULTRA_GROUP_NOT_DISTURB_SET: &str = "/ultragroup/notdisturb/set.json";
+/// 查询默认免打扰配置
+/// 
+/// 频率限制: 100 次/每秒
+pub const ULTRA_GROUP_NOT_DISTURB_GET: &str = "/ultragroup/notdisturb/get.json";
+
 // ============================================================================
 // 聊天室管理 API
 // ============================================================================
@@ -0,0 +1,892 @@
+//! 群组管理（用户信息托管）API
+//!
+//! 开通信息托管后，由融云保存群组资料、成员资料和群权限配置
+
+use crate::core::RongCloud;
+use crate::core::RongCloudError;
+use crate::types::RcResponse;
+use crate::util::deserialize_embedded_json;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::collections::HashMap;
+
+// ============================================================================
+// 权限枚举
+// ============================================================================
+
+/// 申请入群权限
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum GroupJoinPermission {
+    /// 需要群主验证
+    OwnerVerify = 0,
+    /// 不需要验证
+    Free = 1,
+    /// 需要群主或管理员验证
+    OwnerOrManagerVerify = 2,
+    /// 不允许任何人加入
+    NoOneAllowed = 3,
+}
+
+/// 群操作权限
+///
+/// 用于移除成员、邀请成员、修改群资料等操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum GroupOperationPermission {
+    /// 仅群主
+    Owner = 0,
+    /// 群主和管理员
+    OwnerOrManager = 1,
+    /// 所有成员
+    Everyone = 2,
+}
+
+/// 邀请入群处理权限
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum GroupInviteHandlePermission {
+    /// 不需要被邀请人同意
+    Free = 0,
+    /// 需要被邀请人同意
+    InviteeVerify = 1,
+}
+
+/// 群成员资料修改权限
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum GroupMemberInfoEditPermission {
+    /// 群主、管理员和本人
+    OwnerOrManagerOrSelf = 0,
+    /// 群主和本人
+    OwnerOrSelf = 1,
+    /// 仅本人
+    OnlySelf = 2,
+}
+
+/// 群成员角色
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum EntrustGroupRole {
+    /// 群主
+    Owner = 1,
+    /// 管理员
+    Manager = 2,
+    /// 普通成员
+    Member = 3,
+}
+
+// ============================================================================
+// 请求与响应结构体
+// ============================================================================
+
+/// 群组基础资料 (groupProfile)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EntrustGroupBasicProfile {
+    /// 群简介
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub introduction: Option<String>,
+    /// 群公告
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub announcement: Option<String>,
+    /// 群头像 URL
+    #[serde(rename = "portraitUrl", skip_serializing_if = "Option::is_none")]
+    pub portrait_url: Option<String>,
+}
+
+/// 群组权限配置 (permissions)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EntrustGroupPermissions {
+    /// 申请入群权限
+    #[serde(rename = "joinPerm", skip_serializing_if = "Option::is_none")]
+    pub join_perm: Option<GroupJoinPermission>,
+    /// 移除群成员权限
+    #[serde(rename = "removePerm", skip_serializing_if = "Option::is_none")]
+    pub remove_perm: Option<GroupOperationPermission>,
+    /// 邀请成员入群权限
+    #[serde(rename = "memInvitePerm", skip_serializing_if = "Option::is_none")]
+    pub member_invite_perm: Option<GroupOperationPermission>,
+    /// 邀请入群处理权限
+    #[serde(rename = "invitePerm", skip_serializing_if = "Option::is_none")]
+    pub invite_handle_perm: Option<GroupInviteHandlePermission>,
+    /// 修改群资料权限
+    #[serde(rename = "profilePerm", skip_serializing_if = "Option::is_none")]
+    pub profile_perm: Option<GroupOperationPermission>,
+    /// 修改群成员资料权限
+    #[serde(rename = "memProfilePerm", skip_serializing_if = "Option::is_none")]
+    pub member_profile_perm: Option<GroupMemberInfoEditPermission>,
+}
+
+/// 托管群组资料
+///
+/// 用于创建、导入、更新群组，也是群组资料查询的返回结构
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EntrustGroupProfile {
+    /// 群组 ID
+    #[serde(rename = "groupId")]
+    pub group_id: String,
+    /// 群名称
+    #[serde(default)]
+    pub name: Option<String>,
+    /// 群主 ID
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// 群组基础资料
+    #[serde(
+        rename = "groupProfile",
+        default,
+        deserialize_with = "deserialize_embedded_json"
+    )]
+    pub profile: Option<EntrustGroupBasicProfile>,
+    /// 群组权限配置
+    #[serde(default, deserialize_with = "deserialize_embedded_json")]
+    pub permissions: Option<EntrustGroupPermissions>,
+    /// 群组扩展资料
+    #[serde(
+        rename = "groupExtProfile",
+        default,
+        deserialize_with = "deserialize_embedded_json"
+    )]
+    pub ext_profile: Option<HashMap<String, String>>,
+    /// 创建时间（毫秒）
+    #[serde(rename = "createTime", default)]
+    pub create_time: Option<i64>,
+    /// 群成员数量
+    #[serde(rename = "memberCount", default)]
+    pub member_count: Option<i32>,
+}
+
+impl EntrustGroupProfile {
+    pub fn new(group_id: impl Into<String>) -> Self {
+        Self {
+            group_id: group_id.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn owner(mut self, owner: impl Into<String>) -> Self {
+        self.owner = Some(owner.into());
+        self
+    }
+
+    pub fn profile(mut self, profile: EntrustGroupBasicProfile) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+
+    pub fn permissions(mut self, permissions: EntrustGroupPermissions) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+
+    pub fn ext_profile(mut self, ext_profile: HashMap<String, String>) -> Self {
+        self.ext_profile = Some(ext_profile);
+        self
+    }
+
+    /// 转换为表单参数，嵌套资料以 JSON 字符串传递
+    fn to_params(&self) -> Result<Vec<(&'static str, String)>, serde_json::Error> {
+        let mut params = vec![("groupId", self.group_id.clone())];
+        if let Some(name) = &self.name {
+            params.push(("name", name.clone()));
+        }
+        if let Some(owner) = &self.owner {
+            params.push(("owner", owner.clone()));
+        }
+        if let Some(profile) = &self.profile {
+            params.push(("groupProfile", serde_json::to_string(profile)?));
+        }
+        if let Some(permissions) = &self.permissions {
+            params.push(("permissions", serde_json::to_string(permissions)?));
+        }
+        if let Some(ext_profile) = &self.ext_profile {
+            params.push(("groupExtProfile", serde_json::to_string(ext_profile)?));
+        }
+        Ok(params)
+    }
+}
+
+/// 托管群成员资料
+#[derive(Debug, Clone, Deserialize)]
+pub struct EntrustGroupMember {
+    /// 用户 ID
+    #[serde(rename = "userId")]
+    pub user_id: String,
+    /// 群昵称
+    #[serde(default)]
+    pub nickname: Option<String>,
+    /// 扩展信息
+    #[serde(default)]
+    pub extra: Option<String>,
+    /// 成员角色
+    #[serde(default)]
+    pub role: Option<EntrustGroupRole>,
+    /// 入群时间（毫秒）
+    #[serde(rename = "joinTime", default)]
+    pub join_time: Option<i64>,
+}
+
+/// 群组资料批量查询结果
+#[derive(Debug, Deserialize)]
+pub struct EntrustGroupProfileList {
+    /// 群组资料列表
+    #[serde(default)]
+    pub profiles: Vec<EntrustGroupProfile>,
+}
+
+/// 群组分页查询结果
+#[derive(Debug, Deserialize)]
+pub struct EntrustGroupPage {
+    /// 下一页分页标识，为空时表示没有更多数据
+    #[serde(rename = "pageToken", default)]
+    pub page_token: Option<String>,
+    /// 群组列表
+    #[serde(default)]
+    pub groups: Vec<EntrustGroupProfile>,
+}
+
+/// 群成员分页查询结果
+#[derive(Debug, Deserialize)]
+pub struct EntrustGroupMemberPage {
+    /// 下一页分页标识，为空时表示没有更多数据
+    #[serde(rename = "pageToken", default)]
+    pub page_token: Option<String>,
+    /// 群成员总数
+    #[serde(default)]
+    pub total: Option<i64>,
+    /// 群成员列表
+    #[serde(default)]
+    pub members: Vec<EntrustGroupMember>,
+}
+
+/// 指定群成员查询结果
+#[derive(Debug, Deserialize)]
+pub struct EntrustGroupMemberList {
+    /// 群成员列表
+    #[serde(default)]
+    pub members: Vec<EntrustGroupMember>,
+}
+
+/// 特别关注成员
+#[derive(Debug, Clone, Deserialize)]
+pub struct EntrustGroupFollowedMember {
+    /// 被关注的用户 ID
+    #[serde(rename = "userId")]
+    pub user_id: String,
+    /// 关注时间（毫秒）
+    #[serde(default)]
+    pub time: Option<i64>,
+}
+
+/// 特别关注成员查询结果
+#[derive(Debug, Deserialize)]
+pub struct EntrustGroupFollowedList {
+    /// 特别关注成员列表
+    #[serde(default)]
+    pub members: Vec<EntrustGroupFollowedMember>,
+}
+
+/// 群组备注名查询结果
+#[derive(Debug, Deserialize)]
+pub struct EntrustGroupRemarkName {
+    /// 备注名
+    #[serde(rename = "remarkName", default)]
+    pub remark_name: String,
+}
+
+#[derive(Debug, Serialize)]
+struct PageParams<'a> {
+    #[serde(rename = "groupId", skip_serializing_if = "Option::is_none")]
+    group_id: Option<&'a str>,
+    #[serde(rename = "userId", skip_serializing_if = "Option::is_none")]
+    user_id: Option<&'a str>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    role: Option<u8>,
+    #[serde(rename = "pageToken", skip_serializing_if = "Option::is_none")]
+    page_token: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order: Option<i32>,
+}
+
+// ============================================================================
+// API 实现
+// ============================================================================
+
+impl RongCloud {
+    /// Create a group (hosted).
+    ///
+    /// `profile` must contain `name` and `owner`.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/create
+    pub async fn entrust_group_create(
+        &self,
+        profile: &EntrustGroupProfile,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let mut params = profile.to_params()?;
+        if !user_ids.is_empty() {
+            params.push(("userIds", user_ids.join(",")));
+        }
+        self.post(
+            super::endpoints::ENTRUST_GROUP_CREATE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Update group profile (hosted).
+    ///
+    /// Only fields set on `profile` are updated.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/profile/update
+    pub async fn entrust_group_profile_update(
+        &self,
+        profile: &EntrustGroupProfile,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = profile.to_params()?;
+        self.post(
+            super::endpoints::ENTRUST_GROUP_PROFILE_UPDATE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Batch query group profiles (hosted).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/profile/query
+    pub async fn entrust_group_profile_query(
+        &self,
+        group_ids: Vec<&str>,
+    ) -> Result<RcResponse<EntrustGroupProfileList>, RongCloudError> {
+        let params = vec![("groupIds", group_ids.join(","))];
+        self.post(
+            super::endpoints::ENTRUST_GROUP_PROFILE_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Join group (hosted).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/join
+    pub async fn entrust_group_join(
+        &self,
+        group_id: &str,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("userIds", user_ids.join(",")),
+        ];
+        self.post(
+            super::endpoints::ENTRUST_GROUP_JOIN,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Import an existing group into hosted mode.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/import
+    pub async fn entrust_group_import(
+        &self,
+        profile: &EntrustGroupProfile,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = profile.to_params()?;
+        self.post(
+            super::endpoints::ENTRUST_GROUP_IMPORT,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Quit group (hosted).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/quit
+    pub async fn entrust_group_quit(
+        &self,
+        group_id: &str,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("userIds", user_ids.join(",")),
+        ];
+        self.post(
+            super::endpoints::ENTRUST_GROUP_QUIT,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Kick members out of a group (hosted).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/member/kick
+    pub async fn entrust_group_member_kick(
+        &self,
+        group_id: &str,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("userIds", user_ids.join(",")),
+        ];
+        self.post(
+            super::endpoints::ENTRUST_GROUP_MEMBER_KICK,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Kick a user out of all groups (hosted).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/member/kick/all
+    pub async fn entrust_group_member_kick_all(
+        &self,
+        user_id: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![("userId", user_id)];
+        self.post(
+            super::endpoints::ENTRUST_GROUP_MEMBER_KICK_ALL,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Dismiss group (hosted).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/dismiss
+    pub async fn entrust_group_dismiss(
+        &self,
+        group_id: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![("groupId", group_id)];
+        self.post(
+            super::endpoints::ENTRUST_GROUP_DISMISS,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Transfer group ownership (hosted).
+    ///
+    /// If `quit` is true, the previous owner leaves the group after transfer.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/transfer/owner
+    pub async fn entrust_group_transfer_owner(
+        &self,
+        group_id: &str,
+        new_owner: &str,
+        quit: bool,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("newOwner", new_owner.to_string()),
+            ("isQuit", (quit as i32).to_string()),
+        ];
+        self.post(
+            super::endpoints::ENTRUST_GROUP_TRANSFER_OWNER,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Add group managers (hosted).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/manager/add
+    pub async fn entrust_group_manager_add(
+        &self,
+        group_id: &str,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("userIds", user_ids.join(",")),
+        ];
+        self.post(
+            super::endpoints::ENTRUST_GROUP_MANAGER_ADD,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Remove group managers (hosted).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/manager/remove
+    pub async fn entrust_group_manager_remove(
+        &self,
+        group_id: &str,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("userIds", user_ids.join(",")),
+        ];
+        self.post(
+            super::endpoints::ENTRUST_GROUP_MANAGER_REMOVE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    // ========================================================================
+    // 群成员资料
+    // ========================================================================
+
+    /// Query group members with pagination (hosted).
+    ///
+    /// `role` filters members by role; `None` returns all members.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/member/query
+    pub async fn entrust_group_member_query(
+        &self,
+        group_id: &str,
+        role: Option<EntrustGroupRole>,
+        page_token: Option<&str>,
+        size: Option<i32>,
+        order: Option<i32>,
+    ) -> Result<RcResponse<EntrustGroupMemberPage>, RongCloudError> {
+        let params = PageParams {
+            group_id: Some(group_id),
+            user_id: None,
+            role: Some(role.map_or(0, |r| r as u8)),
+            page_token,
+            size,
+            order,
+        };
+        self.post(
+            super::endpoints::ENTRUST_GROUP_MEMBER_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query specific group members (hosted).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/member/specific/query
+    pub async fn entrust_group_member_specific_query(
+        &self,
+        group_id: &str,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<EntrustGroupMemberList>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("userIds", user_ids.join(",")),
+        ];
+        self.post(
+            super::endpoints::ENTRUST_GROUP_MEMBER_SPECIFIC_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Set group member profile (hosted).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/member/set
+    pub async fn entrust_group_member_set(
+        &self,
+        group_id: &str,
+        user_id: &str,
+        nickname: Option<&str>,
+        extra: Option<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let mut params = vec![
+            ("groupId", group_id.to_string()),
+            ("userId", user_id.to_string()),
+        ];
+        if let Some(nickname) = nickname {
+            params.push(("nickname", nickname.to_string()));
+        }
+        if let Some(extra) = extra {
+            params.push(("extra", extra.to_string()));
+        }
+        self.post(
+            super::endpoints::ENTRUST_GROUP_MEMBER_SET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Follow group members for a user (hosted).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/member/follow
+    pub async fn entrust_group_member_follow(
+        &self,
+        group_id: &str,
+        user_id: &str,
+        follow_user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("userId", user_id.to_string()),
+            ("followUserIds", follow_user_ids.join(",")),
+        ];
+        self.post(
+            super::endpoints::ENTRUST_GROUP_MEMBER_FOLLOW,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Unfollow group members for a user (hosted).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/member/unfollow
+    pub async fn entrust_group_member_unfollow(
+        &self,
+        group_id: &str,
+        user_id: &str,
+        follow_user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("userId", user_id.to_string()),
+            ("followUserIds", follow_user_ids.join(",")),
+        ];
+        self.post(
+            super::endpoints::ENTRUST_GROUP_MEMBER_UNFOLLOW,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query members followed by a user in a group (hosted).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/member/followed/get
+    pub async fn entrust_group_member_followed_get(
+        &self,
+        group_id: &str,
+        user_id: &str,
+    ) -> Result<RcResponse<EntrustGroupFollowedList>, RongCloudError> {
+        let params = vec![("groupId", group_id), ("userId", user_id)];
+        self.post(
+            super::endpoints::ENTRUST_GROUP_MEMBER_FOLLOWED_GET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    // ========================================================================
+    // 群组备注名
+    // ========================================================================
+
+    /// Set a user's remark name for a group (hosted).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/remarkname/set
+    pub async fn entrust_group_remark_name_set(
+        &self,
+        user_id: &str,
+        group_id: &str,
+        remark_name: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![
+            ("userId", user_id),
+            ("groupId", group_id),
+            ("remarkName", remark_name),
+        ];
+        self.post(
+            super::endpoints::ENTRUST_GROUP_REMARKNAME_SET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Delete a user's remark name for a group (hosted).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/remarkname/delete
+    pub async fn entrust_group_remark_name_delete(
+        &self,
+        user_id: &str,
+        group_id: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![("userId", user_id), ("groupId", group_id)];
+        self.post(
+            super::endpoints::ENTRUST_GROUP_REMARKNAME_DELETE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query a user's remark name for a group (hosted).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/remarkname/query
+    pub async fn entrust_group_remark_name_query(
+        &self,
+        user_id: &str,
+        group_id: &str,
+    ) -> Result<RcResponse<EntrustGroupRemarkName>, RongCloudError> {
+        let params = vec![("userId", user_id), ("groupId", group_id)];
+        self.post(
+            super::endpoints::ENTRUST_GROUP_REMARKNAME_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    // ========================================================================
+    // 群组分页查询
+    // ========================================================================
+
+    /// Query groups of the app with pagination (hosted).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/query
+    pub async fn entrust_group_query(
+        &self,
+        page_token: Option<&str>,
+        size: Option<i32>,
+        order: Option<i32>,
+    ) -> Result<RcResponse<EntrustGroupPage>, RongCloudError> {
+        let params = PageParams {
+            group_id: None,
+            user_id: None,
+            role: None,
+            page_token,
+            size,
+            order,
+        };
+        self.post(
+            super::endpoints::ENTRUST_GROUP_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query groups joined by a user with pagination (hosted).
+    ///
+    /// `role` filters groups by the user's role; `None` returns all joined groups.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/entrust/joined/query
+    pub async fn entrust_joined_group_query(
+        &self,
+        user_id: &str,
+        role: Option<EntrustGroupRole>,
+        page_token: Option<&str>,
+        size: Option<i32>,
+        order: Option<i32>,
+    ) -> Result<RcResponse<EntrustGroupPage>, RongCloudError> {
+        let params = PageParams {
+            group_id: None,
+            user_id: Some(user_id),
+            role: Some(role.map_or(0, |r| r as u8)),
+            page_token,
+            size,
+            order,
+        };
+        self.post(
+            super::endpoints::ENTRUST_JOINED_GROUP_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::RongCloudConfig;
+    use mockito;
+
+    #[tokio::test]
+    async fn test_entrust_group_create() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock = server
+            .mock("POST", "/entrust/group/create.json")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("groupId".into(), "g1".into()),
+                mockito::Matcher::UrlEncoded("owner".into(), "u1".into()),
+                mockito::Matcher::UrlEncoded("permissions".into(), r#"{"joinPerm":1}"#.into()),
+                mockito::Matcher::UrlEncoded("userIds".into(), "u2,u3".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+
+        let profile = EntrustGroupProfile::new("g1")
+            .name("group1")
+            .owner("u1")
+            .permissions(EntrustGroupPermissions {
+                join_perm: Some(GroupJoinPermission::Free),
+                ..Default::default()
+            });
+        let result = client
+            .entrust_group_create(&profile, vec!["u2", "u3"])
+            .await;
+        mock.assert_async().await;
+        assert!(result.unwrap().is_success());
+    }
+
+    #[tokio::test]
+    async fn test_entrust_group_profile_query() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock = server
+            .mock("POST", "/entrust/group/profile/query.json")
+            .with_status(200)
+            .with_body(
+                r#"{"code": 200, "profiles": [{"groupId": "g1", "name": "group1", "owner": "u1", "groupProfile": "{\"introduction\":\"hi\"}", "permissions": {"joinPerm": 3, "removePerm": 1}}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = client.entrust_group_profile_query(vec!["g1"]).await;
+        mock.assert_async().await;
+
+        let profiles = result.unwrap().data.unwrap().profiles;
+        assert_eq!(profiles.len(), 1);
+        assert_eq!(
+            profiles[0]
+                .profile
+                .as_ref()
+                .unwrap()
+                .introduction
+                .as_deref(),
+            Some("hi")
+        );
+        let permissions = profiles[0].permissions.as_ref().unwrap();
+        assert_eq!(
+            permissions.join_perm,
+            Some(GroupJoinPermission::NoOneAllowed)
+        );
+        assert_eq!(
+            permissions.remove_perm,
+            Some(GroupOperationPermission::OwnerOrManager)
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use crate::core::RongCloud;
 use crate::core::RongCloudError;
 use crate::types::RcResponse;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize)]
 struct FriendAddParams<'a> {
@@ -57,6 +57,75 @@ struct CheckParams<'a> {
     target_ids: String,
 }
 
+/// 好友信息
+#[derive(Debug, Clone, Deserialize)]
+pub struct Friend {
+    /// 好友用户 ID
+    #[serde(rename = "userId")]
+    pub user_id: String,
+    /// 好友备注名
+    #[serde(rename = "remarkName", default)]
+    pub remark_name: Option<String>,
+    /// 好友扩展资料
+    #[serde(rename = "friendExtProfile", default)]
+    pub ext_profile: Option<serde_json::Value>,
+    /// 添加好友时间（毫秒）
+    #[serde(default)]
+    pub time: Option<i64>,
+}
+
+/// 好友分页查询结果
+#[derive(Debug, Deserialize)]
+pub struct FriendPage {
+    /// 下一页分页标识，为空时表示没有更多数据
+    #[serde(rename = "pageToken", default)]
+    pub page_token: Option<String>,
+    /// 好友总数
+    #[serde(rename = "totalCount", default)]
+    pub total_count: Option<i64>,
+    /// 好友列表
+    #[serde(rename = "friendList", default)]
+    pub friends: Vec<Friend>,
+}
+
+/// 好友关系检查结果
+#[derive(Debug, Clone, Deserialize)]
+pub struct FriendCheckResult {
+    /// 目标用户 ID
+    #[serde(rename = "userId")]
+    pub user_id: String,
+    /// 好友关系：0 非好友，1 双向好友，2 单向好友（对方不在我的好友列表），3 单向好友（我不在对方的好友列表）
+    #[serde(default)]
+    pub result: i32,
+}
+
+/// 好友关系检查结果列表
+#[derive(Debug, Deserialize)]
+pub struct FriendCheckList {
+    /// 检查结果
+    #[serde(default)]
+    pub results: Vec<FriendCheckResult>,
+}
+
+/// 用户加好友权限
+#[derive(Debug, Clone, Deserialize)]
+pub struct FriendPermission {
+    /// 用户 ID
+    #[serde(rename = "userId")]
+    pub user_id: String,
+    /// 加好友权限类型
+    #[serde(rename = "permissionType", default)]
+    pub permission_type: i32,
+}
+
+/// 用户加好友权限查询结果
+#[derive(Debug, Deserialize)]
+pub struct FriendPermissionList {
+    /// 权限列表
+    #[serde(rename = "data", default)]
+    pub permissions: Vec<FriendPermission>,
+}
+
 impl RongCloud {
     /// Add a friend.
     pub async fn friend_add(
@@ -132,7 +201,7 @@ impl RongCloud {
         page_token: Option<&str>,
         size: Option<i32>,
         order: Option<i32>,
-    ) -> Result<RcResponse<()>, RongCloudError> {
+    ) -> Result<RcResponse<FriendPage>, RongCloudError> {
         let params = FriendGetListParams {
             user_id,
             page_token,
@@ -152,7 +221,7 @@ impl RongCloud {
         &self,
         user_id: &str,
         target_ids: Vec<&str>,
-    ) -> Result<RcResponse<()>, RongCloudError> {
+    ) -> Result<RcResponse<FriendCheckList>, RongCloudError> {
         let target_ids_str = target_ids.join(",");
         let params = CheckParams {
             user_id,
@@ -195,7 +264,7 @@ impl RongCloud {
     pub async fn friend_permission_get(
         &self,
         user_ids: Vec<&str>,
-    ) -> Result<RcResponse<()>, RongCloudError> {
+    ) -> Result<RcResponse<FriendPermissionList>, RongCloudError> {
         #[derive(Serialize)]
         struct PermGetParams {
             #[serde(rename = "userIds")]
@@ -243,5 +312,24 @@ mod tests {
             .await;
         let _ = client.friend_delete("u1", vec!["u2"]).await;
         mock_del.assert_async().await;
+
+        let mock_list = server
+            .mock("POST", "/friend/get.json")
+            .with_status(200)
+            .with_body(
+                r#"{"code": 200, "pageToken": "p2", "totalCount": 1, "friendList": [{"userId": "u2", "remarkName": "Bob", "time": 1700000000000}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let page = client
+            .friend_get_list("u1", None, Some(50), None)
+            .await
+            .unwrap()
+            .data
+            .unwrap();
+        mock_list.assert_async().await;
+        assert_eq!(page.page_token.as_deref(), Some("p2"));
+        assert_eq!(page.friends[0].remark_name.as_deref(), Some("Bob"));
     }
 }
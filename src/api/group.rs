@@ -1,7 +1,8 @@
+use super::user::{PushRemarkList, remarks_param};
 use crate::core::RongCloud;
 use crate::core::RongCloudError;
 use crate::types::RcResponse;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize)]
 pub struct GroupModel<'a> {
@@ -9,6 +10,85 @@ pub struct GroupModel<'a> {
     pub name: &'a str,
 }
 
+/// 群成员
+#[derive(Debug, Clone, Deserialize)]
+pub struct GroupUser {
+    /// 用户 ID
+    pub id: String,
+}
+
+/// 群成员查询结果
+#[derive(Debug, Deserialize)]
+pub struct GroupUserList {
+    /// 群成员列表
+    #[serde(default)]
+    pub users: Vec<GroupUser>,
+}
+
+/// 群组信息
+#[derive(Debug, Clone, Deserialize)]
+pub struct GroupInfo {
+    /// 群组 ID
+    pub id: String,
+    /// 群组名称
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// 用户所在群组查询结果
+#[derive(Debug, Deserialize)]
+pub struct UserGroupList {
+    /// 群组列表
+    #[serde(default)]
+    pub groups: Vec<GroupInfo>,
+}
+
+/// 群禁言成员
+#[derive(Debug, Clone, Deserialize)]
+pub struct GroupGagUser {
+    /// 用户 ID
+    #[serde(rename = "userId")]
+    pub user_id: String,
+    /// 解除禁言时间，格式为 `yyyy-MM-dd HH:mm:ss`
+    #[serde(rename = "time", default)]
+    pub expire_time: Option<String>,
+}
+
+/// 群禁言成员查询结果
+#[derive(Debug, Deserialize)]
+pub struct GroupGagUserList {
+    /// 禁言成员列表
+    #[serde(default)]
+    pub users: Vec<GroupGagUser>,
+}
+
+/// 群组全体禁言状态
+#[derive(Debug, Clone, Deserialize)]
+pub struct GroupBanStatus {
+    /// 群组 ID
+    #[serde(rename = "groupId")]
+    pub group_id: String,
+    /// 1 为全体禁言，0 为未禁言
+    #[serde(default)]
+    pub stat: i32,
+}
+
+/// 群组全体禁言状态查询结果
+#[derive(Debug, Deserialize)]
+pub struct GroupBanStatusList {
+    /// 群组禁言状态列表
+    #[serde(rename = "groupinfo", default)]
+    pub groups: Vec<GroupBanStatus>,
+}
+
+/// 群禁言白名单查询结果
+#[derive(Debug, Deserialize)]
+pub struct GroupWhitelistUsers {
+    /// 白名单用户 ID 列表
+    #[serde(rename = "userIds", default)]
+    pub user_ids: Vec<String>,
+}
+
 impl RongCloud {
     /// Create a group.
     pub async fn group_create(
@@ -123,7 +203,10 @@ impl RongCloud {
     }
 
     /// Query group users.
-    pub async fn group_user_query(&self, group_id: &str) -> Result<RcResponse<()>, RongCloudError> {
+    pub async fn group_user_query(
+        &self,
+        group_id: &str,
+    ) -> Result<RcResponse<GroupUserList>, RongCloudError> {
         let params = vec![("groupId", group_id)];
         self.post(
             super::endpoints::GROUP_USER_QUERY,
@@ -136,7 +219,10 @@ impl RongCloud {
     /// Query user groups.
     ///
     /// See: https://doc.rongcloud.cn/imserver/server/v1/user/group/query
-    pub async fn user_group_query(&self, user_id: &str) -> Result<RcResponse<()>, RongCloudError> {
+    pub async fn user_group_query(
+        &self,
+        user_id: &str,
+    ) -> Result<RcResponse<UserGroupList>, RongCloudError> {
         let params = vec![("userId", user_id)];
         self.post(
             super::endpoints::USER_GROUP_QUERY,
@@ -200,7 +286,7 @@ impl RongCloud {
     pub async fn group_user_gag_list(
         &self,
         group_id: &str,
-    ) -> Result<RcResponse<()>, RongCloudError> {
+    ) -> Result<RcResponse<GroupGagUserList>, RongCloudError> {
         let params = vec![("groupId", group_id)];
         self.post(
             super::endpoints::GROUP_USER_GAG_LIST,
@@ -242,7 +328,10 @@ impl RongCloud {
     /// Query group ban status.
     ///
     /// See: https://doc.rongcloud.cn/imserver/server/v1/group/ban/query
-    pub async fn group_ban_query(&self, group_id: &str) -> Result<RcResponse<()>, RongCloudError> {
+    pub async fn group_ban_query(
+        &self,
+        group_id: &str,
+    ) -> Result<RcResponse<GroupBanStatusList>, RongCloudError> {
         let params = vec![("groupId", group_id)];
         self.post(
             super::endpoints::GROUP_BAN_QUERY,
@@ -298,7 +387,7 @@ impl RongCloud {
     pub async fn group_user_ban_whitelist_query(
         &self,
         group_id: &str,
-    ) -> Result<RcResponse<()>, RongCloudError> {
+    ) -> Result<RcResponse<GroupWhitelistUsers>, RongCloudError> {
         let params = vec![("groupId", group_id)];
         self.post(
             super::endpoints::GROUP_USER_BAN_WHITELIST_QUERY,
@@ -307,6 +396,69 @@ impl RongCloud {
         )
         .await
     }
+
+    /// Set push remark names of group members.
+    ///
+    /// `remarks` holds `(member_user_id, remark)` pairs, at most 100 per call.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/set-remark-for-push
+    pub async fn group_remarks_set(
+        &self,
+        group_id: &str,
+        remarks: &[(&str, &str)],
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = [
+            ("groupId", group_id.to_string()),
+            ("remarks", remarks_param(remarks)?),
+        ];
+        self.post(
+            super::endpoints::GROUP_REMARKS_SET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Delete the push remark name of a group member.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/delete-remark-for-push
+    pub async fn group_remarks_delete(
+        &self,
+        group_id: &str,
+        user_id: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = [("groupId", group_id), ("userId", user_id)];
+        self.post(
+            super::endpoints::GROUP_REMARKS_DEL,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query the push remark names of group members with pagination.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/group/get-remark-for-push
+    pub async fn group_remarks_get(
+        &self,
+        group_id: &str,
+        page: Option<i32>,
+        size: Option<i32>,
+    ) -> Result<RcResponse<PushRemarkList>, RongCloudError> {
+        let mut params = vec![("groupId", group_id.to_string())];
+        if let Some(page) = page {
+            params.push(("page", page.to_string()));
+        }
+        if let Some(size) = size {
+            params.push(("size", size.to_string()));
+        }
+        self.post(
+            super::endpoints::GROUP_REMARKS_GET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
 }
 
 #[cfg(test)]
@@ -331,4 +483,99 @@ mod tests {
         let _ = client.group_create(vec!["u1"], "g1", "group1").await;
         mock_create.assert_async().await;
     }
+
+    #[tokio::test]
+    async fn test_group_queries() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_users = server
+            .mock("POST", "/group/user/query.json")
+            .with_status(200)
+            .with_body(r#"{"code": 200, "id": "g1", "users": [{"id": "u1"}, {"id": "u2"}]}"#)
+            .create_async()
+            .await;
+
+        let users = client.group_user_query("g1").await.unwrap().data.unwrap();
+        mock_users.assert_async().await;
+        assert_eq!(users.users.len(), 2);
+
+        let mock_gag = server
+            .mock("POST", "/group/user/gag/list.json")
+            .with_status(200)
+            .with_body(
+                r#"{"code": 200, "users": [{"userId": "u1", "time": "2025-09-25 16:12:38"}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let gagged = client
+            .group_user_gag_list("g1")
+            .await
+            .unwrap()
+            .data
+            .unwrap();
+        mock_gag.assert_async().await;
+        assert_eq!(
+            gagged.users[0].expire_time.as_deref(),
+            Some("2025-09-25 16:12:38")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_group_remarks() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_set = server
+            .mock("POST", "/group/remarks/set.json")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("groupId".into(), "g1".into()),
+                mockito::Matcher::UrlEncoded(
+                    "remarks".into(),
+                    r#"[{"id":"u1","remark":"Team lead"}]"#.into(),
+                ),
+            ]))
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+        let mock_del = server
+            .mock("POST", "/group/remarks/del.json")
+            .match_body("groupId=g1&userId=u1")
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+        let mock_get = server
+            .mock("POST", "/group/remarks/get.json")
+            .match_body("groupId=g1")
+            .with_status(200)
+            .with_body(
+                r#"{"code": 200, "total": 1, "users": [{"id": "u1", "remark": "Team lead"}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = client.group_remarks_set("g1", &[("u1", "Team lead")]).await;
+        mock_set.assert_async().await;
+        assert!(result.unwrap().is_success());
+
+        let list = client
+            .group_remarks_get("g1", None, None)
+            .await
+            .unwrap()
+            .data
+            .unwrap();
+        mock_get.assert_async().await;
+        assert_eq!(list.remarks[0].id, "u1");
+
+        let result = client.group_remarks_delete("g1", "u1").await;
+        mock_del.assert_async().await;
+        assert!(result.unwrap().is_success());
+    }
 }
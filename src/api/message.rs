@@ -1,11 +1,13 @@
 //! 消息发送 API
 //!
-//! 包含单聊、群聊、系统消息等发送接口
+//! 包含单聊、群聊、超级群、聊天室、系统消息及状态消息、模板消息、广播消息等发送接口
 
+use super::push::PushExt;
 use crate::core::RongCloud;
 use crate::core::RongCloudError;
-use crate::types::RcResponse;
-use serde::Serialize;
+use crate::messages::Message;
+use crate::types::{ConversationType, RcResponse};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 // ============================================================================
@@ -117,6 +119,11 @@ impl PrivateMessage {
         self
     }
 
+    pub fn with_push_ext(mut self, push_ext: PushExt) -> Result<Self, serde_json::Error> {
+        self.push_ext = Some(push_ext.to_json()?);
+        Ok(self)
+    }
+
     pub fn is_persisted(mut self, value: i32) -> Self {
         self.is_persisted = Some(value);
         self
@@ -243,6 +250,11 @@ impl GroupMessage {
         self
     }
 
+    pub fn with_push_ext(mut self, push_ext: PushExt) -> Result<Self, serde_json::Error> {
+        self.push_ext = Some(push_ext.to_json()?);
+        Ok(self)
+    }
+
     pub fn is_include_sender(mut self, value: i32) -> Self {
         self.is_include_sender = Some(value);
         self
@@ -259,6 +271,281 @@ impl GroupMessage {
     }
 }
 
+/// 状态消息发送选项
+///
+/// 状态消息不存储、不计未读、不推送，适合输入状态等瞬时信令
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StatusMessageOptions {
+    /// 是否过滤黑名单，为 true 时接收方拉黑发送方后不会收到消息
+    pub verify_blacklist: bool,
+    /// 发送者的其他在线端是否也收到消息
+    pub include_sender: bool,
+}
+
+impl StatusMessageOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn verify_blacklist(mut self, value: bool) -> Self {
+        self.verify_blacklist = value;
+        self
+    }
+
+    pub fn include_sender(mut self, value: bool) -> Self {
+        self.include_sender = value;
+        self
+    }
+}
+
+/// 构造状态消息的表单参数
+fn status_message_params<M: Message>(
+    from_user_id: &str,
+    target_key: &'static str,
+    target_ids: &[&str],
+    message: &M,
+    options: &StatusMessageOptions,
+) -> Result<Vec<(&'static str, String)>, serde_json::Error> {
+    let mut params = vec![
+        ("fromUserId", from_user_id.to_string()),
+        ("objectName", message.message_type().to_string()),
+        ("content", message.to_json()?),
+    ];
+    params.extend(target_ids.iter().map(|id| (target_key, id.to_string())));
+    params.push((
+        "verifyBlacklist",
+        (options.verify_blacklist as i32).to_string(),
+    ));
+    params.push((
+        "isIncludeSender",
+        (options.include_sender as i32).to_string(),
+    ));
+    Ok(params)
+}
+
+/// 历史消息日志下载地址
+#[derive(Debug, Clone, Deserialize)]
+pub struct MessageHistoryLog {
+    /// 日志文件下载地址，该小时无消息时为空
+    #[serde(default)]
+    pub url: String,
+    /// 日志对应的时间，格式为 `yyyyMMddHH`
+    #[serde(default)]
+    pub date: String,
+}
+
+/// 超级群消息参数
+///
+/// 超级群消息接口使用 JSON 请求体
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct UltraGroupMessage {
+    /// 发送人 ID (必填)
+    #[serde(rename = "fromUserId")]
+    pub from_user_id: String,
+    /// 目标超级群 ID 列表，最多 3 个 (必填)
+    #[serde(rename = "toGroupIds")]
+    pub to_group_ids: Vec<String>,
+    /// 消息类型 (必填)
+    #[serde(rename = "objectName")]
+    pub object_name: String,
+    /// 消息内容 JSON (必填)
+    pub content: String,
+    /// 频道 ID，不传时发送到不属于任何频道的消息中 (可选)
+    #[serde(rename = "busChannel", skip_serializing_if = "Option::is_none")]
+    pub bus_channel: Option<String>,
+    /// 推送内容 (可选)
+    #[serde(rename = "pushContent", skip_serializing_if = "Option::is_none")]
+    pub push_content: Option<String>,
+    /// 推送附加数据 (可选)
+    #[serde(rename = "pushData", skip_serializing_if = "Option::is_none")]
+    pub push_data: Option<String>,
+    /// 推送扩展配置 (可选)
+    #[serde(rename = "pushExt", skip_serializing_if = "Option::is_none")]
+    pub push_ext: Option<String>,
+    /// 是否在服务端存储消息 (可选)
+    #[serde(rename = "isPersisted", skip_serializing_if = "Option::is_none")]
+    pub is_persisted: Option<i32>,
+    /// 是否算作未读消息数 (可选)
+    #[serde(rename = "isCounted", skip_serializing_if = "Option::is_none")]
+    pub is_counted: Option<i32>,
+    /// 是否为 @消息。0: 非@消息, 1: @消息 (可选)
+    #[serde(rename = "isMentioned", skip_serializing_if = "Option::is_none")]
+    pub is_mentioned: Option<i32>,
+    /// iOS 静默推送开关 (可选)
+    #[serde(rename = "contentAvailable", skip_serializing_if = "Option::is_none")]
+    pub content_available: Option<i32>,
+    /// 是否支持消息扩展 (可选)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expansion: Option<bool>,
+    /// 消息扩展内容 (可选)
+    #[serde(rename = "extraContent", skip_serializing_if = "Option::is_none")]
+    pub extra_content: Option<HashMap<String, String>>,
+    /// 幂等标识 (可选)
+    #[serde(rename = "msgRandom", skip_serializing_if = "Option::is_none")]
+    pub msg_random: Option<i64>,
+}
+
+impl UltraGroupMessage {
+    pub fn new(
+        from_user_id: impl Into<String>,
+        object_name: impl Into<String>,
+        content: impl Into<String>,
+    ) -> Self {
+        Self {
+            from_user_id: from_user_id.into(),
+            object_name: object_name.into(),
+            content: content.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 使用内置或自定义消息类型创建超级群消息
+    pub fn from_message<M: Message>(
+        from_user_id: impl Into<String>,
+        message: &M,
+    ) -> Result<Self, serde_json::Error> {
+        Ok(Self::new(
+            from_user_id,
+            message.message_type(),
+            message.to_json()?,
+        ))
+    }
+
+    pub fn to_group(mut self, group_id: impl Into<String>) -> Self {
+        self.to_group_ids.push(group_id.into());
+        self
+    }
+
+    pub fn to_groups(mut self, group_ids: Vec<String>) -> Self {
+        self.to_group_ids = group_ids;
+        self
+    }
+
+    pub fn bus_channel(mut self, bus_channel: impl Into<String>) -> Self {
+        self.bus_channel = Some(bus_channel.into());
+        self
+    }
+
+    pub fn push_content(mut self, push_content: impl Into<String>) -> Self {
+        self.push_content = Some(push_content.into());
+        self
+    }
+
+    pub fn is_persisted(mut self, value: i32) -> Self {
+        self.is_persisted = Some(value);
+        self
+    }
+
+    pub fn is_mentioned(mut self, value: i32) -> Self {
+        self.is_mentioned = Some(value);
+        self
+    }
+
+    pub fn expansion(mut self, value: bool) -> Self {
+        self.expansion = Some(value);
+        self
+    }
+}
+
+/// 聊天室消息参数
+#[derive(Debug, Clone, Default)]
+pub struct ChatroomMessage {
+    /// 发送人 ID (必填)
+    pub from_user_id: String,
+    /// 目标聊天室 ID 列表 (必填)
+    pub to_chatroom_ids: Vec<String>,
+    /// 消息类型 (必填)
+    pub object_name: String,
+    /// 消息内容 JSON (必填)
+    pub content: String,
+    /// 是否在服务端存储消息。0: 不存储, 1: 存储 (可选)
+    pub is_persisted: Option<i32>,
+    /// 发送者是否也收到消息。0: 不接收, 1: 接收 (可选)
+    pub is_include_sender: Option<i32>,
+    /// 消息优先级。0: 普通, 1: 高优先级，聊天室消息过多时优先保留 (可选)
+    pub priority: Option<i32>,
+    /// 幂等标识 (可选)
+    pub msg_random: Option<i64>,
+}
+
+impl ChatroomMessage {
+    pub fn new(
+        from_user_id: impl Into<String>,
+        object_name: impl Into<String>,
+        content: impl Into<String>,
+    ) -> Self {
+        Self {
+            from_user_id: from_user_id.into(),
+            object_name: object_name.into(),
+            content: content.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 使用内置或自定义消息类型创建聊天室消息
+    pub fn from_message<M: Message>(
+        from_user_id: impl Into<String>,
+        message: &M,
+    ) -> Result<Self, serde_json::Error> {
+        Ok(Self::new(
+            from_user_id,
+            message.message_type(),
+            message.to_json()?,
+        ))
+    }
+
+    pub fn to_chatroom(mut self, chatroom_id: impl Into<String>) -> Self {
+        self.to_chatroom_ids.push(chatroom_id.into());
+        self
+    }
+
+    pub fn to_chatrooms(mut self, chatroom_ids: Vec<String>) -> Self {
+        self.to_chatroom_ids = chatroom_ids;
+        self
+    }
+
+    pub fn is_persisted(mut self, value: i32) -> Self {
+        self.is_persisted = Some(value);
+        self
+    }
+
+    pub fn is_include_sender(mut self, value: i32) -> Self {
+        self.is_include_sender = Some(value);
+        self
+    }
+
+    pub fn priority(mut self, value: i32) -> Self {
+        self.priority = Some(value);
+        self
+    }
+
+    /// 转换为表单参数，多个聊天室以重复的 toChatroomId 传递
+    fn to_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("fromUserId", self.from_user_id.clone()),
+            ("objectName", self.object_name.clone()),
+            ("content", self.content.clone()),
+        ];
+        for id in &self.to_chatroom_ids {
+            params.push(("toChatroomId", id.clone()));
+        }
+        let flags = [
+            ("isPersisted", self.is_persisted),
+            ("isIncludeSender", self.is_include_sender),
+            ("priority", self.priority),
+        ];
+        for (key, value) in flags {
+            if let Some(value) = value {
+                params.push((key, value.to_string()));
+            }
+        }
+        if let Some(msg_random) = self.msg_random {
+            params.push(("msgRandom", msg_random.to_string()));
+        }
+        params
+    }
+}
+
 /// 系统消息参数
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct SystemMessage {
@@ -322,6 +609,337 @@ impl SystemMessage {
         self.to_user_ids = user_ids;
         self
     }
+
+    pub fn with_push_ext(mut self, push_ext: PushExt) -> Result<Self, serde_json::Error> {
+        self.push_ext = Some(push_ext.to_json()?);
+        Ok(self)
+    }
+}
+
+/// 模板消息接收人
+#[derive(Debug, Clone, Default)]
+pub struct TemplateRecipient {
+    /// 接收人 ID
+    pub user_id: String,
+    /// 占位符取值，Key 为不带花括号的占位符名称
+    pub values: HashMap<String, String>,
+    /// 推送内容，可包含占位符 (可选)
+    pub push_content: Option<String>,
+    /// 推送附加数据，可包含占位符 (可选)
+    pub push_data: Option<String>,
+}
+
+impl TemplateRecipient {
+    pub fn new(user_id: impl Into<String>) -> Self {
+        Self {
+            user_id: user_id.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn value(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.values.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn push_content(mut self, push_content: impl Into<String>) -> Self {
+        self.push_content = Some(push_content.into());
+        self
+    }
+
+    pub fn push_data(mut self, push_data: impl Into<String>) -> Self {
+        self.push_data = Some(push_data.into());
+        self
+    }
+}
+
+/// 模板消息参数
+///
+/// 消息内容中的 `{name}` 占位符会按接收人分别替换，一次请求即可发送个性化消息
+#[derive(Debug, Clone, Default)]
+pub struct TemplateMessage {
+    /// 发送人 ID (必填)
+    pub from_user_id: String,
+    /// 消息类型 (必填)
+    pub object_name: String,
+    /// 消息内容模板 JSON (必填)
+    pub content: String,
+    /// 接收人列表 (必填)
+    pub recipients: Vec<TemplateRecipient>,
+    /// 是否过滤黑名单，仅单聊模板消息有效 (可选)
+    pub verify_blacklist: Option<i32>,
+    /// iOS 静默推送开关 (可选)
+    pub content_available: Option<i32>,
+    /// 是否禁用推送 (可选)
+    pub disable_push: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct TemplateMessageBody<'a> {
+    #[serde(rename = "fromUserId")]
+    from_user_id: &'a str,
+    #[serde(rename = "objectName")]
+    object_name: &'a str,
+    content: &'a str,
+    #[serde(rename = "toUserId")]
+    to_user_ids: Vec<&'a str>,
+    values: Vec<HashMap<String, &'a str>>,
+    #[serde(rename = "pushContent")]
+    push_content: Vec<&'a str>,
+    #[serde(rename = "pushData")]
+    push_data: Vec<&'a str>,
+    #[serde(rename = "verifyBlacklist", skip_serializing_if = "Option::is_none")]
+    verify_blacklist: Option<i32>,
+    #[serde(rename = "contentAvailable", skip_serializing_if = "Option::is_none")]
+    content_available: Option<i32>,
+    #[serde(rename = "disablePush", skip_serializing_if = "Option::is_none")]
+    disable_push: Option<bool>,
+}
+
+/// 提取文本中的 `{name}` 占位符名称，名称仅包含字母、数字和下划线
+fn template_placeholders(text: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        rest = &rest[start + 1..];
+        let Some(end) = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')) else {
+            break;
+        };
+        if end > 0 && rest[end..].starts_with('}') && !names.contains(&&rest[..end]) {
+            names.push(&rest[..end]);
+        }
+    }
+    names
+}
+
+impl TemplateMessage {
+    pub fn new(
+        from_user_id: impl Into<String>,
+        object_name: impl Into<String>,
+        content: impl Into<String>,
+    ) -> Self {
+        Self {
+            from_user_id: from_user_id.into(),
+            object_name: object_name.into(),
+            content: content.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 使用内置或自定义消息类型创建模板消息，消息文本中可包含占位符
+    pub fn from_message<M: Message>(
+        from_user_id: impl Into<String>,
+        message: &M,
+    ) -> Result<Self, serde_json::Error> {
+        Ok(Self::new(
+            from_user_id,
+            message.message_type(),
+            message.to_json()?,
+        ))
+    }
+
+    pub fn recipient(mut self, recipient: TemplateRecipient) -> Self {
+        self.recipients.push(recipient);
+        self
+    }
+
+    pub fn recipients(mut self, recipients: Vec<TemplateRecipient>) -> Self {
+        self.recipients = recipients;
+        self
+    }
+
+    pub fn verify_blacklist(mut self, value: i32) -> Self {
+        self.verify_blacklist = Some(value);
+        self
+    }
+
+    pub fn disable_push(mut self, value: bool) -> Self {
+        self.disable_push = Some(value);
+        self
+    }
+
+    /// 校验每个接收人都提供了内容及其推送文本中全部占位符的取值
+    pub fn validate(&self) -> Result<(), RongCloudError> {
+        if self.recipients.is_empty() {
+            return Err(RongCloudError::InvalidParameter(
+                "template message requires at least one recipient".to_string(),
+            ));
+        }
+        let placeholders = template_placeholders(&self.content);
+        for recipient in &self.recipients {
+            let push_texts = [&recipient.push_content, &recipient.push_data];
+            let missing = placeholders
+                .iter()
+                .copied()
+                .chain(
+                    push_texts
+                        .into_iter()
+                        .flatten()
+                        .flat_map(|text| template_placeholders(text)),
+                )
+                .find(|name| !recipient.values.contains_key(*name));
+            if let Some(name) = missing {
+                return Err(RongCloudError::InvalidParameter(format!(
+                    "recipient `{}` has no value for placeholder {{{name}}}",
+                    recipient.user_id
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn to_body(&self) -> TemplateMessageBody<'_> {
+        TemplateMessageBody {
+            from_user_id: &self.from_user_id,
+            object_name: &self.object_name,
+            content: &self.content,
+            to_user_ids: self.recipients.iter().map(|r| r.user_id.as_str()).collect(),
+            values: self
+                .recipients
+                .iter()
+                .map(|r| {
+                    r.values
+                        .iter()
+                        .map(|(name, value)| (format!("{{{name}}}"), value.as_str()))
+                        .collect()
+                })
+                .collect(),
+            push_content: self
+                .recipients
+                .iter()
+                .map(|r| r.push_content.as_deref().unwrap_or_default())
+                .collect(),
+            push_data: self
+                .recipients
+                .iter()
+                .map(|r| r.push_data.as_deref().unwrap_or_default())
+                .collect(),
+            verify_blacklist: self.verify_blacklist,
+            content_available: self.content_available,
+            disable_push: self.disable_push,
+        }
+    }
+}
+
+/// 广播消息推送平台
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BroadcastPlatform {
+    Ios,
+    Android,
+}
+
+impl BroadcastPlatform {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ios => "iOS",
+            Self::Android => "Android",
+        }
+    }
+}
+
+/// 全量用户落地通知参数
+#[derive(Debug, Clone, Default)]
+pub struct BroadcastMessage {
+    /// 发送人 ID (必填)
+    pub from_user_id: String,
+    /// 消息类型 (必填)
+    pub object_name: String,
+    /// 消息内容 JSON (必填)
+    pub content: String,
+    /// 推送内容 (可选)
+    pub push_content: Option<String>,
+    /// 推送附加数据 (可选)
+    pub push_data: Option<String>,
+    /// 仅推送到指定平台，不设置时推送到全部平台 (可选)
+    pub platform: Option<BroadcastPlatform>,
+    /// iOS 静默推送开关。0: 关闭, 1: 开启 (可选)
+    pub content_available: Option<i32>,
+    /// 是否禁用推送 (可选)
+    pub disable_push: Option<bool>,
+}
+
+impl BroadcastMessage {
+    pub fn new(
+        from_user_id: impl Into<String>,
+        object_name: impl Into<String>,
+        content: impl Into<String>,
+    ) -> Self {
+        Self {
+            from_user_id: from_user_id.into(),
+            object_name: object_name.into(),
+            content: content.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 使用内置或自定义消息类型创建广播消息
+    pub fn from_message<M: Message>(
+        from_user_id: impl Into<String>,
+        message: &M,
+    ) -> Result<Self, serde_json::Error> {
+        Ok(Self::new(
+            from_user_id,
+            message.message_type(),
+            message.to_json()?,
+        ))
+    }
+
+    pub fn push_content(mut self, push_content: impl Into<String>) -> Self {
+        self.push_content = Some(push_content.into());
+        self
+    }
+
+    pub fn push_data(mut self, push_data: impl Into<String>) -> Self {
+        self.push_data = Some(push_data.into());
+        self
+    }
+
+    pub fn platform(mut self, platform: BroadcastPlatform) -> Self {
+        self.platform = Some(platform);
+        self
+    }
+
+    pub fn content_available(mut self, value: i32) -> Self {
+        self.content_available = Some(value);
+        self
+    }
+
+    pub fn disable_push(mut self, value: bool) -> Self {
+        self.disable_push = Some(value);
+        self
+    }
+
+    fn to_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("fromUserId", self.from_user_id.clone()),
+            ("objectName", self.object_name.clone()),
+            ("content", self.content.clone()),
+        ];
+        if let Some(push_content) = &self.push_content {
+            params.push(("pushContent", push_content.clone()));
+        }
+        if let Some(push_data) = &self.push_data {
+            params.push(("pushData", push_data.clone()));
+        }
+        if let Some(platform) = self.platform {
+            params.push(("os", platform.as_str().to_string()));
+        }
+        if let Some(content_available) = self.content_available {
+            params.push(("contentAvailable", content_available.to_string()));
+        }
+        if let Some(disable_push) = self.disable_push {
+            params.push(("disablePush", disable_push.to_string()));
+        }
+        params
+    }
+}
+
+/// 广播消息发送结果
+#[derive(Debug, Clone, Deserialize)]
+pub struct BroadcastResult {
+    /// 广播消息 ID，用于撤回
+    #[serde(alias = "messageUID")]
+    pub id: String,
 }
 
 /// 撤回消息参数
@@ -350,103 +968,382 @@ pub struct RecallMessage {
     pub is_admin: Option<i32>,
 }
 
-// ============================================================================
-// API 实现
-// ============================================================================
+/// 消息发送结果
+#[derive(Debug, Deserialize)]
+pub struct MessageUid {
+    /// 消息唯一标识
+    #[serde(rename = "messageUID")]
+    pub message_uid: String,
+}
+
+/// 消息扩展值
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpansionValue {
+    /// 扩展内容
+    pub v: String,
+    /// 最后更新时间戳（毫秒）
+    #[serde(default)]
+    pub ts: i64,
+}
+
+/// 消息扩展查询结果
+#[derive(Debug, Deserialize)]
+pub struct MessageExpansion {
+    /// 扩展内容，Key 为扩展名
+    #[serde(rename = "extraContent", default)]
+    pub extra_content: HashMap<String, ExpansionValue>,
+}
+
+/// 消息扩展 Key 最大长度
+pub const EXPANSION_KEY_MAX_LEN: usize = 32;
+/// 消息扩展 Value 最大长度
+pub const EXPANSION_VALUE_MAX_LEN: usize = 4096;
+/// 单次设置或删除的扩展 Key 数量上限
+pub const EXPANSION_KEYS_MAX_PER_CALL: usize = 100;
+
+/// 校验消息扩展 Key 的数量与长度
+pub(crate) fn validate_expansion_keys<'a>(
+    keys: impl ExactSizeIterator<Item = &'a str>,
+) -> Result<(), RongCloudError> {
+    if keys.len() == 0 || keys.len() > EXPANSION_KEYS_MAX_PER_CALL {
+        return Err(RongCloudError::InvalidParameter(format!(
+            "expansion key count must be between 1 and {EXPANSION_KEYS_MAX_PER_CALL}"
+        )));
+    }
+    for key in keys {
+        if key.is_empty() || key.chars().count() > EXPANSION_KEY_MAX_LEN {
+            return Err(RongCloudError::InvalidParameter(format!(
+                "expansion key `{key}` must be 1 to {EXPANSION_KEY_MAX_LEN} characters"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// 校验消息扩展 Key 与 Value 的数量与长度
+pub(crate) fn validate_expansion_entries(
+    extra: &HashMap<String, String>,
+) -> Result<(), RongCloudError> {
+    validate_expansion_keys(extra.keys().map(String::as_str))?;
+    if let Some((key, _)) = extra
+        .iter()
+        .find(|(_, v)| v.chars().count() > EXPANSION_VALUE_MAX_LEN)
+    {
+        return Err(RongCloudError::InvalidParameter(format!(
+            "expansion value of `{key}` exceeds {EXPANSION_VALUE_MAX_LEN} characters"
+        )));
+    }
+    Ok(())
+}
+
+/// 消息扩展仅支持单聊与群聊会话
+fn validate_expansion_conversation(
+    conversation_type: ConversationType,
+) -> Result<(), RongCloudError> {
+    match conversation_type {
+        ConversationType::Private | ConversationType::Group => Ok(()),
+        other => Err(RongCloudError::InvalidParameter(format!(
+            "message expansion is not supported for {} conversations",
+            other.description()
+        ))),
+    }
+}
+
+// ============================================================================
+// API 实现
+// ============================================================================
+
+impl RongCloud {
+    /// 发送单聊消息 (使用完整参数结构)
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/private/publish
+    pub async fn send_private_message(
+        &self,
+        message: &PrivateMessage,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        self.post(
+            super::endpoints::MESSAGE_PRIVATE_PUBLISH,
+            message,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// 发送单聊消息 (简化版本，兼容旧接口)
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/private/publish
+    pub async fn message_private_publish(
+        &self,
+        from_user_id: &str,
+        to_user_ids: Vec<&str>,
+        object_name: &str,
+        content: &str,
+        push_content: Option<&str>,
+        push_data: Option<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let mut params = vec![
+            ("fromUserId", from_user_id.to_string()),
+            ("objectName", object_name.to_string()),
+            ("content", content.to_string()),
+        ];
+        for id in to_user_ids {
+            params.push(("toUserId", id.to_string()));
+        }
+        if let Some(pc) = push_content {
+            params.push(("pushContent", pc.to_string()));
+        }
+        if let Some(pd) = push_data {
+            params.push(("pushData", pd.to_string()));
+        }
+
+        self.post(
+            super::endpoints::MESSAGE_PRIVATE_PUBLISH,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// 发送群聊消息 (使用完整参数结构)
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/group/publish
+    pub async fn send_group_message(
+        &self,
+        message: &GroupMessage,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        self.post(
+            super::endpoints::MESSAGE_GROUP_PUBLISH,
+            message,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// 发送群聊消息 (简化版本)
+    pub async fn message_group_publish(
+        &self,
+        from_user_id: &str,
+        to_group_ids: Vec<&str>,
+        object_name: &str,
+        content: &str,
+        push_content: Option<&str>,
+        push_data: Option<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let mut params = vec![
+            ("fromUserId", from_user_id.to_string()),
+            ("objectName", object_name.to_string()),
+            ("content", content.to_string()),
+        ];
+        for id in to_group_ids {
+            params.push(("toGroupId", id.to_string()));
+        }
+        if let Some(pc) = push_content {
+            params.push(("pushContent", pc.to_string()));
+        }
+        if let Some(pd) = push_data {
+            params.push(("pushData", pd.to_string()));
+        }
+
+        self.post(
+            super::endpoints::MESSAGE_GROUP_PUBLISH,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// 发送单聊状态消息
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/private/status
+    pub async fn send_private_status_message<M: Message>(
+        &self,
+        from_user_id: &str,
+        to_user_ids: Vec<&str>,
+        message: &M,
+        options: &StatusMessageOptions,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params =
+            status_message_params(from_user_id, "toUserId", &to_user_ids, message, options)?;
+        self.post(
+            super::endpoints::STATUS_MESSAGE_PRIVATE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// 发送群聊状态消息
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/group/status
+    pub async fn send_group_status_message<M: Message>(
+        &self,
+        from_user_id: &str,
+        to_group_ids: Vec<&str>,
+        message: &M,
+        options: &StatusMessageOptions,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params =
+            status_message_params(from_user_id, "toGroupId", &to_group_ids, message, options)?;
+        self.post(
+            super::endpoints::STATUS_MESSAGE_GROUP,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// 发送超级群消息
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/ultragroup/publish
+    pub async fn send_ultra_group_message(
+        &self,
+        message: &UltraGroupMessage,
+    ) -> Result<RcResponse<MessageUid>, RongCloudError> {
+        self.post(
+            super::endpoints::MESSAGE_ULTRA_GROUP_PUBLISH,
+            message,
+            "application/json",
+        )
+        .await
+    }
 
-impl RongCloud {
-    /// 发送单聊消息 (使用完整参数结构)
+    /// 发送聊天室消息
     ///
-    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/private/publish
-    pub async fn send_private_message(
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/chatroom/publish
+    pub async fn send_chatroom_message(
         &self,
-        message: &PrivateMessage,
+        message: &ChatroomMessage,
     ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = message.to_params();
         self.post(
-            super::endpoints::MESSAGE_PRIVATE_PUBLISH,
-            message,
+            super::endpoints::MESSAGE_CHATROOM_PUBLISH,
+            &params,
             "application/x-www-form-urlencoded",
         )
         .await
     }
 
-    /// 发送单聊消息 (简化版本，兼容旧接口)
+    /// 向应用内所有聊天室广播消息
     ///
-    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/private/publish
-    pub async fn message_private_publish(
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/chatroom/broadcast
+    pub async fn send_chatroom_broadcast<M: Message>(
         &self,
         from_user_id: &str,
-        to_user_ids: Vec<&str>,
-        object_name: &str,
-        content: &str,
-        push_content: Option<&str>,
-        push_data: Option<&str>,
+        message: &M,
+        is_include_sender: bool,
     ) -> Result<RcResponse<()>, RongCloudError> {
-        let mut params = vec![
+        let params = vec![
             ("fromUserId", from_user_id.to_string()),
-            ("objectName", object_name.to_string()),
-            ("content", content.to_string()),
+            ("objectName", message.message_type().to_string()),
+            ("content", message.to_json()?),
+            ("isIncludeSender", (is_include_sender as i32).to_string()),
         ];
-        for id in to_user_ids {
-            params.push(("toUserId", id.to_string()));
-        }
-        if let Some(pc) = push_content {
-            params.push(("pushContent", pc.to_string()));
-        }
-        if let Some(pd) = push_data {
-            params.push(("pushData", pd.to_string()));
-        }
-
         self.post(
-            super::endpoints::MESSAGE_PRIVATE_PUBLISH,
+            super::endpoints::MESSAGE_CHATROOM_BROADCAST,
             &params,
             "application/x-www-form-urlencoded",
         )
         .await
     }
 
-    /// 发送群聊消息 (使用完整参数结构)
+    /// 发送单聊模板消息
     ///
-    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/group/publish
-    pub async fn send_group_message(
+    /// 发送前会校验所有接收人的占位符取值是否完整
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/private/template
+    pub async fn send_private_template_message(
         &self,
-        message: &GroupMessage,
+        message: &TemplateMessage,
     ) -> Result<RcResponse<()>, RongCloudError> {
+        message.validate()?;
         self.post(
-            super::endpoints::MESSAGE_GROUP_PUBLISH,
-            message,
+            super::endpoints::MESSAGE_PRIVATE_TEMPLATE,
+            &message.to_body(),
+            "application/json",
+        )
+        .await
+    }
+
+    /// 发送系统模板消息
+    ///
+    /// 发送前会校验所有接收人的占位符取值是否完整
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/system/template
+    pub async fn send_system_template_message(
+        &self,
+        message: &TemplateMessage,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        message.validate()?;
+        self.post(
+            super::endpoints::MESSAGE_SYSTEM_TEMPLATE,
+            &message.to_body(),
+            "application/json",
+        )
+        .await
+    }
+
+    /// 发送全量用户落地通知
+    ///
+    /// 返回的 ID 可用于 [`RongCloud::recall_broadcast_message`] 撤回
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/broadcast
+    pub async fn send_broadcast_message(
+        &self,
+        message: &BroadcastMessage,
+    ) -> Result<RcResponse<BroadcastResult>, RongCloudError> {
+        let params = message.to_params();
+        self.post(
+            super::endpoints::MESSAGE_BROADCAST,
+            &params,
             "application/x-www-form-urlencoded",
         )
         .await
     }
 
-    /// 发送群聊消息 (简化版本)
-    pub async fn message_group_publish(
+    /// 发送在线用户广播，离线用户不会收到
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/online-broadcast
+    pub async fn send_online_broadcast_message<M: Message>(
         &self,
         from_user_id: &str,
-        to_group_ids: Vec<&str>,
-        object_name: &str,
-        content: &str,
-        push_content: Option<&str>,
-        push_data: Option<&str>,
+        message: &M,
+    ) -> Result<RcResponse<BroadcastResult>, RongCloudError> {
+        let params = vec![
+            ("fromUserId", from_user_id.to_string()),
+            ("objectName", message.message_type().to_string()),
+            ("content", message.to_json()?),
+        ];
+        self.post(
+            super::endpoints::MESSAGE_ONLINE_BROADCAST,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// 撤回全量用户落地通知
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/broadcast/recall
+    pub async fn recall_broadcast_message(
+        &self,
+        from_user_id: &str,
+        broadcast_id: &str,
     ) -> Result<RcResponse<()>, RongCloudError> {
-        let mut params = vec![
+        let content = serde_json::json!({
+            "messageUId": broadcast_id,
+            "isAdmin": 0,
+            "isDelete": 0,
+        });
+        let params = vec![
             ("fromUserId", from_user_id.to_string()),
-            ("objectName", object_name.to_string()),
+            (
+                "objectName",
+                crate::messages::message_type::RECALL_CMD.to_string(),
+            ),
             ("content", content.to_string()),
         ];
-        for id in to_group_ids {
-            params.push(("toGroupId", id.to_string()));
-        }
-        if let Some(pc) = push_content {
-            params.push(("pushContent", pc.to_string()));
-        }
-        if let Some(pd) = push_data {
-            params.push(("pushData", pd.to_string()));
-        }
-
         self.post(
-            super::endpoints::MESSAGE_GROUP_PUBLISH,
+            super::endpoints::MESSAGE_BROADCAST_RECALL,
             &params,
             "application/x-www-form-urlencoded",
         )
@@ -529,13 +1426,95 @@ impl RongCloud {
         .await
     }
 
+    /// 设置单聊、群聊消息扩展
+    ///
+    /// 发送时需开启 `expansion`，`user_id` 为操作者 ID
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/expansion/set
+    pub async fn message_expansion_set(
+        &self,
+        msg_uid: &str,
+        user_id: &str,
+        conversation_type: ConversationType,
+        target_id: &str,
+        extra: &HashMap<String, String>,
+        is_sync_sender: bool,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        validate_expansion_conversation(conversation_type)?;
+        validate_expansion_entries(extra)?;
+        let params = vec![
+            ("msgUID", msg_uid.to_string()),
+            ("userId", user_id.to_string()),
+            ("conversationType", (conversation_type as u8).to_string()),
+            ("targetId", target_id.to_string()),
+            ("extraKeyVal", serde_json::to_string(extra)?),
+            ("isSyncSender", (is_sync_sender as i32).to_string()),
+        ];
+        self.post(
+            super::endpoints::MESSAGE_EXPANSION_SET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// 删除单聊、群聊消息扩展
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/expansion/delete
+    pub async fn message_expansion_delete(
+        &self,
+        msg_uid: &str,
+        user_id: &str,
+        conversation_type: ConversationType,
+        target_id: &str,
+        keys: Vec<&str>,
+        is_sync_sender: bool,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        validate_expansion_conversation(conversation_type)?;
+        validate_expansion_keys(keys.iter().copied())?;
+        let params = vec![
+            ("msgUID", msg_uid.to_string()),
+            ("userId", user_id.to_string()),
+            ("conversationType", (conversation_type as u8).to_string()),
+            ("targetId", target_id.to_string()),
+            ("extraKey", serde_json::to_string(&keys)?),
+            ("isSyncSender", (is_sync_sender as i32).to_string()),
+        ];
+        self.post(
+            super::endpoints::MESSAGE_EXPANSION_DELETE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// 获取单聊、群聊消息扩展
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/message/expansion/query
+    pub async fn message_expansion_query(
+        &self,
+        msg_uid: &str,
+        page_no: Option<i32>,
+    ) -> Result<RcResponse<MessageExpansion>, RongCloudError> {
+        let mut params = vec![("msgUID", msg_uid.to_string())];
+        if let Some(page_no) = page_no {
+            params.push(("pageNo", page_no.to_string()));
+        }
+        self.post(
+            super::endpoints::MESSAGE_EXPANSION_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
     /// 获取历史消息日志
     ///
     /// See: https://doc.rongcloud.cn/imserver/server/v1/message/history
     pub async fn message_history(
         &self,
         date: &str, // 格式: 2014010101 (年月日时)
-    ) -> Result<RcResponse<()>, RongCloudError> {
+    ) -> Result<RcResponse<MessageHistoryLog>, RongCloudError> {
         let params = vec![("date", date)];
         self.post(
             super::endpoints::MESSAGE_HISTORY,
@@ -566,6 +1545,7 @@ impl RongCloud {
 mod tests {
     use super::*;
     use crate::core::RongCloudConfig;
+    use crate::messages::{TxtMessage, TypingStatusMessage};
 
     #[tokio::test]
     async fn test_message_publish() {
@@ -638,4 +1618,257 @@ mod tests {
         assert_eq!(msg.to_group_ids.len(), 2);
         assert_eq!(msg.is_mentioned, Some(1));
     }
+
+    #[test]
+    fn test_with_push_ext() {
+        use crate::api::push::OppoPushConfig;
+
+        let ext = PushExt::new()
+            .title("Notice")
+            .oppo(OppoPushConfig::new().channel_id("im"));
+        let msg = SystemMessage::new("admin", "RC:TxtMsg", "content")
+            .with_push_ext(ext)
+            .unwrap();
+        assert_eq!(
+            msg.push_ext.as_deref(),
+            Some(r#"{"title":"Notice","pushConfigs":[{"OPPO":{"channelId":"im"}}]}"#)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chatroom_message_publish() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_publish = server
+            .mock("POST", "/message/chatroom/publish.json")
+            .match_body(
+                "fromUserId=bot&objectName=RC%3ATxtMsg&content=%7B%22content%22%3A%22hello%22%7D\
+                 &toChatroomId=c1&toChatroomId=c2&isIncludeSender=0&priority=1",
+            )
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+
+        let msg = ChatroomMessage::from_message("bot", &TxtMessage::new("hello"))
+            .unwrap()
+            .to_chatroom("c1")
+            .to_chatroom("c2")
+            .is_include_sender(0)
+            .priority(1);
+        let result = client.send_chatroom_message(&msg).await;
+        mock_publish.assert_async().await;
+        assert!(result.unwrap().is_success());
+
+        let mock_broadcast = server
+            .mock("POST", "/message/chatroom/broadcast.json")
+            .match_body(mockito::Matcher::UrlEncoded(
+                "isIncludeSender".into(),
+                "1".into(),
+            ))
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+
+        let result = client
+            .send_chatroom_broadcast("bot", &TxtMessage::new("notice"), true)
+            .await;
+        mock_broadcast.assert_async().await;
+        assert!(result.unwrap().is_success());
+    }
+
+    #[tokio::test]
+    async fn test_message_expansion() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_set = server
+            .mock("POST", "/message/expansion/set.json")
+            .match_body(
+                "msgUID=M1&userId=u1&conversationType=3&targetId=g1\
+                 &extraKeyVal=%7B%22like%22%3A%223%22%7D&isSyncSender=0",
+            )
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+
+        let extra = HashMap::from([("like".to_string(), "3".to_string())]);
+        let result = client
+            .message_expansion_set("M1", "u1", ConversationType::Group, "g1", &extra, false)
+            .await;
+        mock_set.assert_async().await;
+        assert!(result.unwrap().is_success());
+
+        let too_long = HashMap::from([("k".repeat(EXPANSION_KEY_MAX_LEN + 1), "v".to_string())]);
+        let result = client
+            .message_expansion_set("M1", "u1", ConversationType::Group, "g1", &too_long, false)
+            .await;
+        assert!(matches!(result, Err(RongCloudError::InvalidParameter(_))));
+
+        let result = client
+            .message_expansion_delete(
+                "M1",
+                "u1",
+                ConversationType::ChatRoom,
+                "c1",
+                vec!["like"],
+                false,
+            )
+            .await;
+        assert!(matches!(result, Err(RongCloudError::InvalidParameter(_))));
+
+        let mock_query = server
+            .mock("POST", "/message/expansion/query.json")
+            .with_status(200)
+            .with_body(
+                r#"{"code": 200, "extraContent": {"like": {"v": "3", "ts": 1700000000000}}}"#,
+            )
+            .create_async()
+            .await;
+
+        let expansion = client
+            .message_expansion_query("M1", None)
+            .await
+            .unwrap()
+            .data
+            .unwrap();
+        mock_query.assert_async().await;
+        assert_eq!(expansion.extra_content["like"].v, "3");
+    }
+
+    #[tokio::test]
+    async fn test_status_message_publish() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_private = server
+            .mock("POST", "/statusmessage/private/publish.json")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("objectName".into(), "RC:TypSts".into()),
+                mockito::Matcher::UrlEncoded("toUserId".into(), "u2".into()),
+                mockito::Matcher::UrlEncoded("verifyBlacklist".into(), "1".into()),
+                mockito::Matcher::UrlEncoded("isIncludeSender".into(), "0".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+
+        let typing = TypingStatusMessage::new("RC:TxtMsg");
+        let options = StatusMessageOptions::new().verify_blacklist(true);
+        let result = client
+            .send_private_status_message("u1", vec!["u2"], &typing, &options)
+            .await;
+        mock_private.assert_async().await;
+        assert!(result.unwrap().is_success());
+
+        let mock_group = server
+            .mock("POST", "/statusmessage/group/publish.json")
+            .match_body(mockito::Matcher::UrlEncoded(
+                "toGroupId".into(),
+                "g1".into(),
+            ))
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+
+        let result = client
+            .send_group_status_message("u1", vec!["g1"], &typing, &StatusMessageOptions::new())
+            .await;
+        mock_group.assert_async().await;
+        assert!(result.unwrap().is_success());
+    }
+
+    #[tokio::test]
+    async fn test_template_message() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock = server
+            .mock("POST", "/message/private/publish_template.json")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"toUserId": ["u1", "u2"], "values": [{"{order}": "A1"}, {"{order}": "B2"}], "pushContent": ["Order {order}", ""]}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+
+        let msg = TemplateMessage::from_message("shop", &TxtMessage::new("Order {order} shipped"))
+            .unwrap()
+            .recipient(
+                TemplateRecipient::new("u1")
+                    .value("order", "A1")
+                    .push_content("Order {order}"),
+            )
+            .recipient(TemplateRecipient::new("u2").value("order", "B2"));
+        let result = client.send_private_template_message(&msg).await;
+        mock.assert_async().await;
+        assert!(result.unwrap().is_success());
+
+        let incomplete = msg.recipient(TemplateRecipient::new("u3"));
+        let result = client.send_system_template_message(&incomplete).await;
+        assert!(matches!(result, Err(RongCloudError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn test_template_placeholders() {
+        let content = r#"{"content":"Hi {name}, order {order_id} is {name}'s"}"#;
+        assert_eq!(template_placeholders(content), vec!["name", "order_id"]);
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_message() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_send = server
+            .mock("POST", "/message/broadcast.json")
+            .match_body(
+                "fromUserId=admin&objectName=RC%3ATxtMsg&content=%7B%22content%22%3A%22hi%22%7D\
+                 &os=iOS&contentAvailable=1",
+            )
+            .with_status(200)
+            .with_body(r#"{"code": 200, "id": "B1"}"#)
+            .create_async()
+            .await;
+
+        let msg = BroadcastMessage::from_message("admin", &TxtMessage::new("hi"))
+            .unwrap()
+            .platform(BroadcastPlatform::Ios)
+            .content_available(1);
+        let result = client.send_broadcast_message(&msg).await.unwrap();
+        mock_send.assert_async().await;
+        let id = result.data.unwrap().id;
+        assert_eq!(id, "B1");
+
+        let mock_recall = server
+            .mock("POST", "/message/broadcast/recall.json")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("objectName".into(), "RC:RcCmd".into()),
+                mockito::Matcher::Regex("B1".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+
+        let result = client.recall_broadcast_message("admin", &id).await;
+        mock_recall.assert_async().await;
+        assert!(result.unwrap().is_success());
+    }
 }
@@ -1,8 +1,11 @@
 pub mod chatroom;
+pub mod conversation;
 pub mod endpoints;
+pub mod entrust_group;
 pub mod friend;
 pub mod group;
 pub mod message;
 pub mod push;
 pub mod sensitive;
+pub mod ultragroup;
 pub mod user;
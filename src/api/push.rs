@@ -1,14 +1,16 @@
+//! 推送服务 API
+//!
+//! 包含按标签、用户、包名等条件的推送、不落地通知、推送 Plus 及推送统计
+
 use crate::core::RongCloud;
 use crate::core::RongCloudError;
+use crate::messages::Message;
 use crate::types::RcResponse;
-use serde::Serialize;
-use serde_json::Value;
-
-#[derive(Debug, Serialize, Clone, PartialEq)]
-// RongCloud might expect ["ios", "android"] or generic strings.
-// Documentation says: platform: ["ios", "android"] lowercase usually, or "all" string if broadcast?
-// Java SDK uses String array. Let's assume lowercase string literals.
-// If valid values are known: ios, android
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::HashMap;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum Platform {
     Ios,
@@ -17,37 +19,1148 @@ pub enum Platform {
     Pc,
 }
 
-#[derive(Debug, Serialize)]
+// ============================================================================
+// 推送目标
+// ============================================================================
+
+/// 推送目标
+#[derive(Debug, Clone, PartialEq)]
+pub enum Audience {
+    /// 全部用户
+    All,
+    /// 同时拥有全部标签的用户（标签取交集）
+    TagsAnd(Vec<String>),
+    /// 拥有任一标签的用户（标签取并集）
+    TagsOr(Vec<String>),
+    /// 指定用户 ID
+    UserIds(Vec<String>),
+    /// 指定应用包名的用户
+    PackageName(String),
+}
+
+impl Serialize for Audience {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(2))?;
+        match self {
+            Self::All => {}
+            Self::TagsAnd(tags) => map.serialize_entry("tag", tags)?,
+            Self::TagsOr(tags) => map.serialize_entry("tag_or", tags)?,
+            Self::UserIds(user_ids) => map.serialize_entry("userid", user_ids)?,
+            Self::PackageName(name) => map.serialize_entry("packageName", name)?,
+        }
+        map.serialize_entry("is_to_all", &matches!(self, Self::All))?;
+        map.end()
+    }
+}
+
+// ============================================================================
+// 通知内容
+// ============================================================================
+
+/// iOS 平台通知设置，未设置的字段使用通用通知内容
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IosNotification {
+    /// 通知标题
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// 通知内容
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert: Option<String>,
+    /// 附加数据
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<HashMap<String, String>>,
+    /// 通知分组 ID
+    #[serde(rename = "thread-id", skip_serializing_if = "Option::is_none")]
+    pub thread_id: Option<String>,
+    /// 角标数量
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub badge: Option<i32>,
+    /// 静默推送开关。0: 关闭, 1: 开启
+    #[serde(rename = "contentAvailable", skip_serializing_if = "Option::is_none")]
+    pub content_available: Option<i32>,
+}
+
+impl IosNotification {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn alert(mut self, alert: impl Into<String>) -> Self {
+        self.alert = Some(alert.into());
+        self
+    }
+
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.extras
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    pub fn thread_id(mut self, thread_id: impl Into<String>) -> Self {
+        self.thread_id = Some(thread_id.into());
+        self
+    }
+
+    pub fn badge(mut self, badge: i32) -> Self {
+        self.badge = Some(badge);
+        self
+    }
+
+    pub fn content_available(mut self, value: i32) -> Self {
+        self.content_available = Some(value);
+        self
+    }
+}
+
+/// Android 平台通知设置，未设置的字段使用通用通知内容
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AndroidNotification {
+    /// 通知标题
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// 通知内容
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert: Option<String>,
+    /// 附加数据
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<HashMap<String, String>>,
+}
+
+impl AndroidNotification {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn alert(mut self, alert: impl Into<String>) -> Self {
+        self.alert = Some(alert.into());
+        self
+    }
+
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.extras
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+}
+
+/// 推送通知内容
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Notification {
+    /// 通知标题 (可选)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// 通用通知内容 (必填)
+    pub alert: String,
+    /// iOS 平台设置 (可选)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ios: Option<IosNotification>,
+    /// Android 平台设置 (可选)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub android: Option<AndroidNotification>,
+}
+
+impl Notification {
+    pub fn new(alert: impl Into<String>) -> Self {
+        Self {
+            alert: alert.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn ios(mut self, ios: IosNotification) -> Self {
+        self.ios = Some(ios);
+        self
+    }
+
+    pub fn android(mut self, android: AndroidNotification) -> Self {
+        self.android = Some(android);
+        self
+    }
+}
+
+/// 随推送下发的消息
+#[derive(Debug, Clone, Serialize)]
+pub struct PushMessage {
+    /// 消息内容 JSON
+    pub content: String,
+    /// 消息类型
+    #[serde(rename = "objectName")]
+    pub object_name: String,
+}
+
+impl PushMessage {
+    /// 使用内置或自定义消息类型创建推送消息
+    pub fn from_message<M: Message>(message: &M) -> Result<Self, serde_json::Error> {
+        Ok(Self {
+            content: message.to_json()?,
+            object_name: message.message_type().to_string(),
+        })
+    }
+}
+
+// ============================================================================
+// 消息推送扩展 (pushExt)
+// ============================================================================
+
+/// 厂商通知重要级别
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PushImportance {
+    /// 服务与通讯类消息
+    Normal,
+    /// 资讯营销类消息
+    Low,
+}
+
+/// APNs 推送设置
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ApnsPushConfig {
+    /// 通知分组 ID
+    #[serde(rename = "thread-id", skip_serializing_if = "Option::is_none")]
+    pub thread_id: Option<String>,
+    /// 通知合并 ID，相同 ID 的通知只展示最新一条
+    #[serde(rename = "apns-collapse-id", skip_serializing_if = "Option::is_none")]
+    pub collapse_id: Option<String>,
+    /// 通知类别
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// 富媒体地址
+    #[serde(rename = "richMediaUri", skip_serializing_if = "Option::is_none")]
+    pub rich_media_uri: Option<String>,
+}
+
+impl ApnsPushConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn thread_id(mut self, thread_id: impl Into<String>) -> Self {
+        self.thread_id = Some(thread_id.into());
+        self
+    }
+
+    pub fn collapse_id(mut self, collapse_id: impl Into<String>) -> Self {
+        self.collapse_id = Some(collapse_id.into());
+        self
+    }
+
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+
+    pub fn rich_media_uri(mut self, uri: impl Into<String>) -> Self {
+        self.rich_media_uri = Some(uri.into());
+        self
+    }
+}
+
+/// 华为推送设置
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct HuaweiPushConfig {
+    /// 通知渠道 ID
+    #[serde(rename = "channelId", skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<String>,
+    /// 通知重要级别
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub importance: Option<PushImportance>,
+    /// 通知右侧大图标 URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// 自分类消息类型，如 IM、VOIP
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+}
+
+impl HuaweiPushConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn channel_id(mut self, channel_id: impl Into<String>) -> Self {
+        self.channel_id = Some(channel_id.into());
+        self
+    }
+
+    pub fn importance(mut self, importance: PushImportance) -> Self {
+        self.importance = Some(importance);
+        self
+    }
+
+    pub fn image(mut self, url: impl Into<String>) -> Self {
+        self.image = Some(url.into());
+        self
+    }
+
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+}
+
+/// 荣耀推送设置
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct HonorPushConfig {
+    /// 通知重要级别
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub importance: Option<PushImportance>,
+    /// 通知右侧大图标 URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+}
+
+impl HonorPushConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn importance(mut self, importance: PushImportance) -> Self {
+        self.importance = Some(importance);
+        self
+    }
+
+    pub fn image(mut self, url: impl Into<String>) -> Self {
+        self.image = Some(url.into());
+        self
+    }
+}
+
+/// 小米推送设置
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct XiaomiPushConfig {
+    /// 通知渠道 ID
+    #[serde(rename = "channelId", skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<String>,
+    /// 通知大图标 URL
+    #[serde(rename = "large_icon_uri", skip_serializing_if = "Option::is_none")]
+    pub large_icon_uri: Option<String>,
+}
+
+impl XiaomiPushConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn channel_id(mut self, channel_id: impl Into<String>) -> Self {
+        self.channel_id = Some(channel_id.into());
+        self
+    }
+
+    pub fn large_icon_uri(mut self, url: impl Into<String>) -> Self {
+        self.large_icon_uri = Some(url.into());
+        self
+    }
+}
+
+/// OPPO 推送设置
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct OppoPushConfig {
+    /// 通知渠道 ID
+    #[serde(rename = "channelId", skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<String>,
+}
+
+impl OppoPushConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn channel_id(mut self, channel_id: impl Into<String>) -> Self {
+        self.channel_id = Some(channel_id.into());
+        self
+    }
+}
+
+/// vivo 推送设置
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct VivoPushConfig {
+    /// 消息分类。0: 运营消息, 1: 系统消息
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+    /// 二级分类，如 IM、ORDER
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+}
+
+impl VivoPushConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn classification(mut self, system: bool) -> Self {
+        self.classification = Some(if system { "1" } else { "0" }.to_string());
+        self
+    }
+
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+}
+
+/// FCM 推送设置
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FcmPushConfig {
+    /// 通知渠道 ID
+    #[serde(rename = "channelId", skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<String>,
+    /// 通知合并 key
+    #[serde(rename = "collapse_key", skip_serializing_if = "Option::is_none")]
+    pub collapse_key: Option<String>,
+    /// 通知图片 URL
+    #[serde(rename = "imageUrl", skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+}
+
+impl FcmPushConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn channel_id(mut self, channel_id: impl Into<String>) -> Self {
+        self.channel_id = Some(channel_id.into());
+        self
+    }
+
+    pub fn collapse_key(mut self, collapse_key: impl Into<String>) -> Self {
+        self.collapse_key = Some(collapse_key.into());
+        self
+    }
+
+    pub fn image_url(mut self, url: impl Into<String>) -> Self {
+        self.image_url = Some(url.into());
+        self
+    }
+}
+
+/// 单个厂商的推送设置，序列化为 `{"HW": {...}}` 形式
+#[derive(Debug, Clone, Serialize)]
+pub enum VendorPushConfig {
+    #[serde(rename = "APNs")]
+    Apns(ApnsPushConfig),
+    #[serde(rename = "HW")]
+    Huawei(HuaweiPushConfig),
+    #[serde(rename = "HONOR")]
+    Honor(HonorPushConfig),
+    #[serde(rename = "MI")]
+    Xiaomi(XiaomiPushConfig),
+    #[serde(rename = "OPPO")]
+    Oppo(OppoPushConfig),
+    #[serde(rename = "VIVO")]
+    Vivo(VivoPushConfig),
+    #[serde(rename = "FCM")]
+    Fcm(FcmPushConfig),
+}
+
+/// 消息推送扩展配置，序列化后填入各消息的 `pushExt` 字段
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PushExt {
+    /// 推送标题
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// 推送模板 ID
+    #[serde(rename = "templateId", skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<String>,
+    /// 是否强制显示推送内容。0: 否, 1: 是
+    #[serde(
+        rename = "forceShowPushContent",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub force_show_push_content: Option<i32>,
+    /// 各厂商推送设置
+    #[serde(rename = "pushConfigs", skip_serializing_if = "Vec::is_empty")]
+    pub push_configs: Vec<VendorPushConfig>,
+}
+
+impl PushExt {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn template_id(mut self, template_id: impl Into<String>) -> Self {
+        self.template_id = Some(template_id.into());
+        self
+    }
+
+    pub fn force_show_push_content(mut self, value: bool) -> Self {
+        self.force_show_push_content = Some(value as i32);
+        self
+    }
+
+    pub fn apns(mut self, config: ApnsPushConfig) -> Self {
+        self.push_configs.push(VendorPushConfig::Apns(config));
+        self
+    }
+
+    pub fn huawei(mut self, config: HuaweiPushConfig) -> Self {
+        self.push_configs.push(VendorPushConfig::Huawei(config));
+        self
+    }
+
+    pub fn honor(mut self, config: HonorPushConfig) -> Self {
+        self.push_configs.push(VendorPushConfig::Honor(config));
+        self
+    }
+
+    pub fn xiaomi(mut self, config: XiaomiPushConfig) -> Self {
+        self.push_configs.push(VendorPushConfig::Xiaomi(config));
+        self
+    }
+
+    pub fn oppo(mut self, config: OppoPushConfig) -> Self {
+        self.push_configs.push(VendorPushConfig::Oppo(config));
+        self
+    }
+
+    pub fn vivo(mut self, config: VivoPushConfig) -> Self {
+        self.push_configs.push(VendorPushConfig::Vivo(config));
+        self
+    }
+
+    pub fn fcm(mut self, config: FcmPushConfig) -> Self {
+        self.push_configs.push(VendorPushConfig::Fcm(config));
+        self
+    }
+
+    /// 序列化为 `pushExt` 参数使用的 JSON 字符串
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+}
+
+// ============================================================================
+// 推送请求与结果
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize)]
 pub struct PushModel {
     pub platform: Vec<Platform>,
-    pub audience: Value,
+    pub audience: Audience,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub notification: Option<Value>,
+    pub notification: Option<Notification>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<Value>,
+    pub message: Option<PushMessage>,
+    /// 消息发送人 ID，携带 `message` 时必填
+    #[serde(rename = "fromuserid", skip_serializing_if = "Option::is_none")]
+    pub from_user_id: Option<String>,
+}
+
+impl PushModel {
+    /// 创建推送到 iOS 与 Android 平台的请求
+    pub fn new(audience: Audience) -> Self {
+        Self {
+            platform: vec![Platform::Ios, Platform::Android],
+            audience,
+            notification: None,
+            message: None,
+            from_user_id: None,
+        }
+    }
+
+    pub fn platforms(mut self, platforms: Vec<Platform>) -> Self {
+        self.platform = platforms;
+        self
+    }
+
+    pub fn notification(mut self, notification: Notification) -> Self {
+        self.notification = Some(notification);
+        self
+    }
+
+    pub fn message(mut self, from_user_id: impl Into<String>, message: PushMessage) -> Self {
+        self.from_user_id = Some(from_user_id.into());
+        self.message = Some(message);
+        self
+    }
+}
+
+/// 推送结果
+#[derive(Debug, Clone, Deserialize)]
+pub struct PushResult {
+    /// 推送 ID
+    #[serde(default)]
+    pub id: String,
+}
+
+#[derive(Serialize)]
+struct PushUserNotification<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<&'a str>,
+    #[serde(rename = "pushContent")]
+    push_content: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ios: Option<&'a IosNotification>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    android: Option<&'a AndroidNotification>,
+}
+
+#[derive(Serialize)]
+struct PushUserBody<'a> {
+    #[serde(rename = "userIds")]
+    user_ids: Vec<&'a str>,
+    notification: PushUserNotification<'a>,
+}
+
+// ============================================================================
+// 推送 Plus
+// ============================================================================
+
+pub use crate::core::{CUSTOM_PUSH_DAILY_LIMIT, CUSTOM_PUSH_HOURLY_LIMIT};
+
+/// 推送 Plus Android 平台设置，可按厂商通道分别配置
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CustomAndroidNotification {
+    /// 附加数据
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<HashMap<String, String>>,
+    /// 华为通道设置
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hw: Option<HuaweiPushConfig>,
+    /// 荣耀通道设置
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub honor: Option<HonorPushConfig>,
+    /// 小米通道设置
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mi: Option<XiaomiPushConfig>,
+    /// OPPO 通道设置
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oppo: Option<OppoPushConfig>,
+    /// vivo 通道设置
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vivo: Option<VivoPushConfig>,
+    /// FCM 通道设置
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fcm: Option<FcmPushConfig>,
+}
+
+impl CustomAndroidNotification {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.extras
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    pub fn huawei(mut self, config: HuaweiPushConfig) -> Self {
+        self.hw = Some(config);
+        self
+    }
+
+    pub fn honor(mut self, config: HonorPushConfig) -> Self {
+        self.honor = Some(config);
+        self
+    }
+
+    pub fn xiaomi(mut self, config: XiaomiPushConfig) -> Self {
+        self.mi = Some(config);
+        self
+    }
+
+    pub fn oppo(mut self, config: OppoPushConfig) -> Self {
+        self.oppo = Some(config);
+        self
+    }
+
+    pub fn vivo(mut self, config: VivoPushConfig) -> Self {
+        self.vivo = Some(config);
+        self
+    }
+
+    pub fn fcm(mut self, config: FcmPushConfig) -> Self {
+        self.fcm = Some(config);
+        self
+    }
+}
+
+/// 推送 Plus 通知内容
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CustomNotification {
+    /// 通知标题 (可选)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// 通知内容 (必填)
+    pub alert: String,
+    /// iOS 平台设置 (可选)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ios: Option<IosNotification>,
+    /// Android 平台设置 (可选)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub android: Option<CustomAndroidNotification>,
+}
+
+impl CustomNotification {
+    pub fn new(alert: impl Into<String>) -> Self {
+        Self {
+            alert: alert.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn ios(mut self, ios: IosNotification) -> Self {
+        self.ios = Some(ios);
+        self
+    }
+
+    pub fn android(mut self, android: CustomAndroidNotification) -> Self {
+        self.android = Some(android);
+        self
+    }
+}
+
+/// 推送 Plus 请求
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomPush {
+    pub platform: Vec<Platform>,
+    pub audience: Audience,
+    pub notification: CustomNotification,
+    /// 定时发送时间，Unix 时间戳（毫秒），不设置则立即发送 (可选)
+    #[serde(rename = "sendTime", skip_serializing_if = "Option::is_none")]
+    pub send_time: Option<i64>,
+    /// 推送离线保存时长（秒），超时未送达则丢弃 (可选)
+    #[serde(rename = "timeToLive", skip_serializing_if = "Option::is_none")]
+    pub time_to_live: Option<i64>,
+}
+
+impl CustomPush {
+    /// 创建推送到 iOS 与 Android 平台的请求
+    pub fn new(audience: Audience, notification: CustomNotification) -> Self {
+        Self {
+            platform: vec![Platform::Ios, Platform::Android],
+            audience,
+            notification,
+            send_time: None,
+            time_to_live: None,
+        }
+    }
+
+    pub fn platforms(mut self, platforms: Vec<Platform>) -> Self {
+        self.platform = platforms;
+        self
+    }
+
+    pub fn send_time(mut self, timestamp_ms: i64) -> Self {
+        self.send_time = Some(timestamp_ms);
+        self
+    }
+
+    pub fn time_to_live(mut self, seconds: i64) -> Self {
+        self.time_to_live = Some(seconds);
+        self
+    }
+}
+
+// ============================================================================
+// 推送统计
+// ============================================================================
+
+/// 统计接口调用间隔（频率限制 1 次/每秒）
+const STAT_REQUEST_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// 按日推送聚合统计
+///
+/// 字段均为扁平结构，可直接逐行输出为 CSV 或 JSON Lines
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PushDayStat {
+    /// 统计日期，格式 `YYYY-MM-DD`
+    #[serde(default)]
+    pub date: String,
+    /// 推送数
+    #[serde(rename = "pushCount", default)]
+    pub push_count: i64,
+    /// 到达数
+    #[serde(rename = "arriveCount", default)]
+    pub arrive_count: i64,
+    /// 点击数
+    #[serde(rename = "clickCount", default)]
+    pub click_count: i64,
+}
+
+/// 单次推送统计
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PushIdStat {
+    /// 推送 ID
+    #[serde(rename = "pushId", default)]
+    pub push_id: String,
+    /// 推送数
+    #[serde(rename = "pushCount", default)]
+    pub push_count: i64,
+    /// 到达数
+    #[serde(rename = "arriveCount", default)]
+    pub arrive_count: i64,
+    /// 点击数
+    #[serde(rename = "clickCount", default)]
+    pub click_count: i64,
+}
+
+impl PushDayStat {
+    /// CSV 表头，与 `to_csv_row` 的列顺序一致
+    pub const CSV_HEADER: &'static str = "date,pushCount,arriveCount,clickCount";
+
+    pub fn to_csv_row(&self) -> String {
+        format!(
+            "{},{},{},{}",
+            csv_field(&self.date),
+            self.push_count,
+            self.arrive_count,
+            self.click_count
+        )
+    }
+}
+
+impl PushIdStat {
+    /// CSV 表头，与 `to_csv_row` 的列顺序一致
+    pub const CSV_HEADER: &'static str = "pushId,pushCount,arriveCount,clickCount";
+
+    pub fn to_csv_row(&self) -> String {
+        format!(
+            "{},{},{},{}",
+            csv_field(&self.push_id),
+            self.push_count,
+            self.arrive_count,
+            self.click_count
+        )
+    }
+}
+
+/// 日期区间统计中单日的查询结果
+///
+/// 无数据的日期保留为 `Ok(None)`，查询失败的日期保留错误，不影响其他日期
+#[derive(Debug)]
+pub struct PushDayOutcome {
+    /// 统计日期，格式 `YYYY-MM-DD`
+    pub date: String,
+    /// 当日统计，无数据时为 `None`
+    pub result: Result<Option<PushDayStat>, RongCloudError>,
+}
+
+impl PushDayOutcome {
+    /// CSV 表头，与 `to_csv_row` 的列顺序一致
+    pub const CSV_HEADER: &'static str = "date,status,pushCount,arriveCount,clickCount,error";
+
+    /// 结果状态：`ok`、`noData` 或 `error`
+    pub fn status(&self) -> &'static str {
+        match &self.result {
+            Ok(Some(_)) => "ok",
+            Ok(None) => "noData",
+            Err(_) => "error",
+        }
+    }
+
+    pub fn stat(&self) -> Option<&PushDayStat> {
+        self.result.as_ref().ok().and_then(Option::as_ref)
+    }
+
+    /// 无数据或失败的日期计数列留空
+    pub fn to_csv_row(&self) -> String {
+        let counts = match self.stat() {
+            Some(stat) => format!(
+                "{},{},{}",
+                stat.push_count, stat.arrive_count, stat.click_count
+            ),
+            None => ",,".to_string(),
+        };
+        let error = match &self.result {
+            Err(e) => csv_field(&e.to_string()),
+            Ok(_) => String::new(),
+        };
+        format!(
+            "{},{},{},{}",
+            csv_field(&self.date),
+            self.status(),
+            counts,
+            error
+        )
+    }
+}
+
+impl Serialize for PushDayOutcome {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("date", &self.date)?;
+        map.serialize_entry("status", self.status())?;
+        match &self.result {
+            Ok(Some(stat)) => {
+                map.serialize_entry("pushCount", &stat.push_count)?;
+                map.serialize_entry("arriveCount", &stat.arrive_count)?;
+                map.serialize_entry("clickCount", &stat.click_count)?;
+            }
+            Ok(None) => {}
+            Err(e) => map.serialize_entry("error", &e.to_string())?,
+        }
+        map.end()
+    }
+}
+
+/// 推送统计接口的 `data` 字段
+#[derive(Debug, Deserialize)]
+struct PushStatResult<T> {
+    #[serde(default)]
+    data: Option<T>,
+}
+
+/// 取出统计数据，非成功状态码转换为 `RongCloudError::Api`
+fn stat_data<T>(response: RcResponse<PushStatResult<T>>) -> Result<Option<T>, RongCloudError> {
+    if !response.is_success() {
+        return Err(RongCloudError::Api {
+            code: response.code,
+            msg: response.msg,
+        });
+    }
+    Ok(response.data.and_then(|r| r.data))
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// 将 `YYYY-MM-DD` 解析为自 1970-01-01 起的天数
+fn parse_stat_date(date: &str) -> Result<i64, RongCloudError> {
+    let invalid = || RongCloudError::InvalidParameter(format!("invalid date: {}", date));
+    let mut parts = date.splitn(3, '-');
+    let mut next = |range: std::ops::RangeInclusive<i64>| -> Result<i64, RongCloudError> {
+        parts
+            .next()
+            .filter(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|p| p.parse().ok())
+            .filter(|v| range.contains(v))
+            .ok_or_else(invalid)
+    };
+    let (year, month, day) = (next(1..=9999)?, next(1..=12)?, next(1..=31)?);
+    let days = days_from_civil(year, month, day);
+    // 回转校验，拒绝 2月30日 等不存在的日期
+    if civil_from_days(days) != (year, month, day) {
+        return Err(invalid());
+    }
+    Ok(days)
+}
+
+fn format_stat_date(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// 推送 Plus 请求是否确定未被融云受理，仅此时归还本地配额
+fn custom_push_not_accepted(err: &RongCloudError) -> bool {
+    match err {
+        RongCloudError::Http(e) => e.is_connect(),
+        RongCloudError::Api { code, .. } => (400..500).contains(code),
+        _ => false,
+    }
 }
 
 impl RongCloud {
     /// Send push notification.
     ///
     /// See: https://doc.rongcloud.cn/imserver/server/v1/push/send
-    pub async fn push(&self, payload: &PushModel) -> Result<RcResponse<()>, RongCloudError> {
+    pub async fn push(
+        &self,
+        payload: &PushModel,
+    ) -> Result<RcResponse<PushResult>, RongCloudError> {
         self.post(super::endpoints::PUSH, payload, "application/json")
             .await
     }
 
     /// Send broadcast.
     ///
+    /// A broadcast delivers `message` to the audience as an in-app message
+    /// in addition to the notification, so `message` must be set.
+    ///
     /// See: https://doc.rongcloud.cn/imserver/server/v1/push/broadcast
-    /// Note: The Java SDK uses /push.json for broadcast as well, but with different payload structure.
-    /// Here we reuse the generic push method as the endpoint is the same.
     pub async fn push_broadcast(
         &self,
         payload: &PushModel,
-    ) -> Result<RcResponse<()>, RongCloudError> {
+    ) -> Result<RcResponse<PushResult>, RongCloudError> {
+        if payload.message.is_none() || payload.from_user_id.is_none() {
+            return Err(RongCloudError::InvalidParameter(
+                "broadcast requires a message and its sender".to_string(),
+            ));
+        }
         self.post(super::endpoints::PUSH, payload, "application/json")
             .await
     }
+
+    /// Send a notification to specific users without storing a message.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/push/user
+    pub async fn push_to_users(
+        &self,
+        user_ids: Vec<&str>,
+        notification: &Notification,
+    ) -> Result<RcResponse<PushResult>, RongCloudError> {
+        let body = PushUserBody {
+            user_ids,
+            notification: PushUserNotification {
+                title: notification.title.as_deref(),
+                push_content: &notification.alert,
+                ios: notification.ios.as_ref(),
+                android: notification.android.as_ref(),
+            },
+        };
+        self.post(super::endpoints::PUSH_USER, &body, "application/json")
+            .await
+    }
+
+    /// Send a custom push (Push Plus).
+    ///
+    /// Calls beyond the rolling hourly or daily quota are rejected locally with
+    /// `RongCloudError::QuotaExceeded`. The quota slot is returned only when the
+    /// request provably never reached RongCloud: a connect error or an HTTP 4xx
+    /// rejection. Timeouts, 5xx and unparsable responses keep the slot.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/push/custom
+    pub async fn push_custom(
+        &self,
+        payload: &CustomPush,
+    ) -> Result<RcResponse<PushResult>, RongCloudError> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        self.custom_push_quota
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .try_acquire(now)?;
+        let result = self
+            .post(super::endpoints::PUSH_CUSTOM, payload, "application/json")
+            .await;
+        if result.as_ref().is_err_and(custom_push_not_accepted) {
+            self.custom_push_quota
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .release(now);
+        }
+        result
+    }
+
+    /// Query aggregated push statistics of a day (`YYYY-MM-DD`).
+    ///
+    /// Returns `None` when there is no data for the day.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/push/stat-day
+    pub async fn push_stat_day(&self, date: &str) -> Result<Option<PushDayStat>, RongCloudError> {
+        let params = [("date", date)];
+        let response = self
+            .post(
+                super::endpoints::STAT_GET_DAY_PUSH_DATA,
+                &params,
+                "application/x-www-form-urlencoded",
+            )
+            .await?;
+        stat_data(response)
+    }
+
+    /// Query push statistics of a single push ID.
+    ///
+    /// Returns `None` when there is no data for the push ID.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/push/stat-push-id
+    pub async fn push_stat_by_id(
+        &self,
+        push_id: &str,
+    ) -> Result<Option<PushIdStat>, RongCloudError> {
+        let params = [("pushId", push_id)];
+        let response = self
+            .post(
+                super::endpoints::STAT_GET_PUSH_ID_DATA,
+                &params,
+                "application/x-www-form-urlencoded",
+            )
+            .await?;
+        stat_data(response)
+    }
+
+    /// Query daily push statistics for every day from `start_date` to
+    /// `end_date` inclusive, one request per second.
+    ///
+    /// Returns one outcome per day. A failed day does not stop the range, and
+    /// days without data are kept as `None` rather than zero rows.
+    pub async fn push_stat_day_range(
+        &self,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<PushDayOutcome>, RongCloudError> {
+        let start = parse_stat_date(start_date)?;
+        let end = parse_stat_date(end_date)?;
+        if start > end {
+            return Err(RongCloudError::InvalidParameter(
+                "start_date must not be after end_date".to_string(),
+            ));
+        }
+
+        let mut outcomes = Vec::with_capacity((end - start + 1) as usize);
+        for days in start..=end {
+            if days > start {
+                tokio::time::sleep(STAT_REQUEST_INTERVAL).await;
+            }
+            let date = format_stat_date(days);
+            let result = self.push_stat_day(&date).await.map(|stat| {
+                stat.map(|mut stat| {
+                    stat.date = date.clone();
+                    stat
+                })
+            });
+            outcomes.push(PushDayOutcome { date, result });
+        }
+        Ok(outcomes)
+    }
 }
 
 #[cfg(test)]
@@ -66,18 +1179,321 @@ mod tests {
 
         let mock_push = server
             .mock("POST", "/push.json")
+            .match_body(mockito::Matcher::Json(json!({
+                "platform": ["ios"],
+                "audience": {"tag_or": ["vip", "beta"], "is_to_all": false},
+                "notification": {
+                    "alert": "hello",
+                    "ios": {"thread-id": "promo", "badge": 1}
+                }
+            })))
             .with_status(200)
-            .with_body(r#"{"code": 200}"#)
+            .with_body(r#"{"code": 200, "id": "P1"}"#)
             .create_async()
             .await;
 
-        let payload = PushModel {
-            platform: vec![Platform::Ios],
-            audience: json!({"is_to_all": true}),
-            notification: Some(json!({"alert": "hello"})),
-            message: None,
-        };
-        let _ = client.push(&payload).await;
+        let payload = PushModel::new(Audience::TagsOr(vec!["vip".into(), "beta".into()]))
+            .platforms(vec![Platform::Ios])
+            .notification(
+                Notification::new("hello").ios(IosNotification::new().thread_id("promo").badge(1)),
+            );
+        let result = client.push(&payload).await;
         mock_push.assert_async().await;
+        assert_eq!(result.unwrap().data.unwrap().id, "P1");
+
+        let result = client.push_broadcast(&payload).await;
+        assert!(matches!(result, Err(RongCloudError::InvalidParameter(_))));
+    }
+
+    #[tokio::test]
+    async fn test_push_to_users() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock = server
+            .mock("POST", "/push/user.json")
+            .match_body(mockito::Matcher::Json(json!({
+                "userIds": ["u1", "u2"],
+                "notification": {
+                    "title": "Order",
+                    "pushContent": "shipped",
+                    "android": {"extras": {"orderId": "A1"}}
+                }
+            })))
+            .with_status(200)
+            .with_body(r#"{"code": 200, "id": "P2"}"#)
+            .create_async()
+            .await;
+
+        let notification = Notification::new("shipped")
+            .title("Order")
+            .android(AndroidNotification::new().extra("orderId", "A1"));
+        let result = client.push_to_users(vec!["u1", "u2"], &notification).await;
+        mock.assert_async().await;
+        assert_eq!(result.unwrap().data.unwrap().id, "P2");
+    }
+
+    #[tokio::test]
+    async fn test_push_custom() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock = server
+            .mock("POST", "/push/custom.json")
+            .match_body(mockito::Matcher::Json(json!({
+                "platform": ["ios", "android"],
+                "audience": {"tag": ["vip"], "is_to_all": false},
+                "notification": {
+                    "title": "Sale",
+                    "alert": "50% off",
+                    "android": {"hw": {"channelId": "marketing", "importance": "LOW"}}
+                },
+                "sendTime": 1700000000000i64
+            })))
+            .with_status(200)
+            .with_body(r#"{"code": 200, "id": "P3"}"#)
+            .create_async()
+            .await;
+
+        let payload = CustomPush::new(
+            Audience::TagsAnd(vec!["vip".into()]),
+            CustomNotification::new("50% off").title("Sale").android(
+                CustomAndroidNotification::new().huawei(
+                    HuaweiPushConfig::new()
+                        .channel_id("marketing")
+                        .importance(PushImportance::Low),
+                ),
+            ),
+        )
+        .send_time(1_700_000_000_000);
+        let result = client.push_custom(&payload).await;
+        mock.assert_async().await;
+        assert_eq!(result.unwrap().data.unwrap().id, "P3");
+    }
+
+    fn custom_push_payload() -> CustomPush {
+        CustomPush::new(Audience::All, CustomNotification::new("hello"))
+    }
+
+    #[tokio::test]
+    async fn test_push_custom_quota_exceeded() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock = server
+            .mock("POST", "/push/custom.json")
+            .with_status(200)
+            .with_body(r#"{"code": 200, "id": "P1"}"#)
+            .expect(CUSTOM_PUSH_HOURLY_LIMIT as usize)
+            .create_async()
+            .await;
+
+        let payload = custom_push_payload();
+        for _ in 0..CUSTOM_PUSH_HOURLY_LIMIT {
+            client.push_custom(&payload).await.unwrap();
+        }
+        let result = client.push_custom(&payload).await;
+        assert!(matches!(result, Err(RongCloudError::QuotaExceeded(_))));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_push_custom_quota_release() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+        let payload = custom_push_payload();
+
+        // 4xx 拒绝表示请求未被受理，配额归还，后续调用仍会发出
+        let mock_rejected = server
+            .mock("POST", "/push/custom.json")
+            .with_status(400)
+            .with_body(r#"{"code": 1002, "errorMessage": "invalid"}"#)
+            .expect(CUSTOM_PUSH_HOURLY_LIMIT as usize + 1)
+            .create_async()
+            .await;
+        for _ in 0..=CUSTOM_PUSH_HOURLY_LIMIT {
+            let result = client.push_custom(&payload).await;
+            assert!(matches!(result, Err(RongCloudError::Api { code: 400, .. })));
+        }
+        mock_rejected.assert_async().await;
+        mock_rejected.remove_async().await;
+
+        // HTTP 200 但响应无法解析时推送可能已受理，配额不归还
+        let mock_accepted = server
+            .mock("POST", "/push/custom.json")
+            .with_status(200)
+            .with_body("accepted")
+            .expect(CUSTOM_PUSH_HOURLY_LIMIT as usize)
+            .create_async()
+            .await;
+        for _ in 0..CUSTOM_PUSH_HOURLY_LIMIT {
+            let result = client.push_custom(&payload).await;
+            assert!(matches!(result, Err(RongCloudError::Serialization(_))));
+        }
+        let result = client.push_custom(&payload).await;
+        assert!(matches!(result, Err(RongCloudError::QuotaExceeded(_))));
+        mock_accepted.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_push_stat_day_range() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_first = server
+            .mock("POST", "/stat/getDayPushData")
+            .match_body("date=2024-02-28")
+            .with_status(200)
+            .with_body(
+                r#"{"code": 200, "data": {"pushCount": 10, "arriveCount": 8, "clickCount": 2}}"#,
+            )
+            .create_async()
+            .await;
+        let mock_failed = server
+            .mock("POST", "/stat/getDayPushData")
+            .match_body("date=2024-02-29")
+            .with_status(200)
+            .with_body(r#"{"code": 1002, "msg": "busy"}"#)
+            .create_async()
+            .await;
+        let mock_empty = server
+            .mock("POST", "/stat/getDayPushData")
+            .match_body("date=2024-03-01")
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+
+        let rows = client
+            .push_stat_day_range("2024-02-28", "2024-03-01")
+            .await
+            .unwrap();
+        mock_first.assert_async().await;
+        mock_failed.assert_async().await;
+        mock_empty.assert_async().await;
+        assert_eq!(rows.len(), 3);
+
+        // 失败的日期不影响前后日期的结果
+        assert_eq!(rows[0].stat().unwrap().to_csv_row(), "2024-02-28,10,8,2");
+        assert_eq!(rows[0].to_csv_row(), "2024-02-28,ok,10,8,2,");
+        assert!(matches!(
+            rows[1].result,
+            Err(RongCloudError::Api { code: 1002, .. })
+        ));
+        assert_eq!(rows[1].status(), "error");
+        assert!(rows[2].result.as_ref().unwrap().is_none());
+        assert_eq!(rows[2].to_csv_row(), "2024-03-01,noData,,,,");
+        assert_eq!(
+            serde_json::to_string(&rows[0]).unwrap(),
+            r#"{"date":"2024-02-28","status":"ok","pushCount":10,"arriveCount":8,"clickCount":2}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&rows[2]).unwrap(),
+            r#"{"date":"2024-03-01","status":"noData"}"#
+        );
+
+        let result = client.push_stat_day_range("2024-02-30", "2024-03-01").await;
+        assert!(matches!(result, Err(RongCloudError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn test_parse_stat_date() {
+        assert_eq!(parse_stat_date("1970-01-01").unwrap(), 0);
+        assert_eq!(
+            format_stat_date(parse_stat_date("2024-02-29").unwrap()),
+            "2024-02-29"
+        );
+        for date in [
+            "9000000000000000000-01-01",
+            "2024-01-9223372036854775807",
+            "2024-13-01",
+            "0000-01-01",
+            "2024-+1-01",
+            "2024-01",
+        ] {
+            assert!(
+                matches!(
+                    parse_stat_date(date),
+                    Err(RongCloudError::InvalidParameter(_))
+                ),
+                "{date}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_push_stat_by_id() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock = server
+            .mock("POST", "/stat/getPushIdData")
+            .match_body("pushId=P1")
+            .with_status(200)
+            .with_body(
+                r#"{"code": 200, "data": {"pushId": "P1", "pushCount": 5, "arriveCount": 4, "clickCount": 1}}"#,
+            )
+            .create_async()
+            .await;
+
+        let stat = client.push_stat_by_id("P1").await.unwrap().unwrap();
+        mock.assert_async().await;
+        assert_eq!(stat.arrive_count, 4);
+        assert_eq!(
+            PushIdStat::CSV_HEADER,
+            "pushId,pushCount,arriveCount,clickCount"
+        );
+    }
+
+    #[test]
+    fn test_push_ext_serialize() {
+        let ext = PushExt::new()
+            .title("New order")
+            .force_show_push_content(true)
+            .apns(ApnsPushConfig::new().thread_id("orders").collapse_id("A1"))
+            .huawei(
+                HuaweiPushConfig::new()
+                    .channel_id("im")
+                    .importance(PushImportance::Normal)
+                    .category("IM"),
+            )
+            .vivo(VivoPushConfig::new().classification(true));
+        let value: serde_json::Value = serde_json::from_str(&ext.to_json().unwrap()).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "title": "New order",
+                "forceShowPushContent": 1,
+                "pushConfigs": [
+                    {"APNs": {"thread-id": "orders", "apns-collapse-id": "A1"}},
+                    {"HW": {"channelId": "im", "importance": "NORMAL", "category": "IM"}},
+                    {"VIVO": {"classification": "1"}}
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_audience_serialize() {
+        assert_eq!(
+            serde_json::to_value(Audience::All).unwrap(),
+            json!({"is_to_all": true})
+        );
+        assert_eq!(
+            serde_json::to_value(Audience::UserIds(vec!["u1".into()])).unwrap(),
+            json!({"userid": ["u1"], "is_to_all": false})
+        );
     }
 }
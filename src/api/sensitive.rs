@@ -20,6 +20,14 @@ pub struct SensitiveWordModel {
     pub replace_word: Option<String>,
 }
 
+/// 敏感词查询结果
+#[derive(Debug, Deserialize)]
+pub struct SensitiveWordList {
+    /// 敏感词列表
+    #[serde(default)]
+    pub words: Vec<SensitiveWordModel>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct AddSensitiveWordsModel {
     pub words: Vec<SensitiveWordModel>,
@@ -36,10 +44,10 @@ impl RongCloud {
         replace_word: Option<&str>,
     ) -> Result<RcResponse<()>, RongCloudError> {
         let mut params = vec![("word".to_string(), word.to_string())];
-        if bind_type == SensitiveType::Replace {
-            if let Some(rw) = replace_word {
-                params.push(("replaceWord".to_string(), rw.to_string()));
-            }
+        if bind_type == SensitiveType::Replace
+            && let Some(rw) = replace_word
+        {
+            params.push(("replaceWord".to_string(), rw.to_string()));
         }
 
         self.post(
@@ -72,7 +80,7 @@ impl RongCloud {
     pub async fn sensitive_word_list(
         &self,
         typ: Option<SensitiveType>,
-    ) -> Result<RcResponse<()>, RongCloudError> {
+    ) -> Result<RcResponse<SensitiveWordList>, RongCloudError> {
         let mut params = Vec::new();
         if let Some(t) = typ {
             let val = t as u8;
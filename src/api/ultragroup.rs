@@ -0,0 +1,1642 @@
+//! 超级群管理 API
+//!
+//! 包含超级群的创建、加入、退出、解散、刷新、频道管理、私有频道、用户组、禁言、免打扰及消息接口
+
+use super::message::{MessageExpansion, validate_expansion_entries, validate_expansion_keys};
+use crate::core::RongCloud;
+use crate::core::RongCloudError;
+use crate::messages::{Message, MessageContent};
+use crate::types::{NotificationLevel, RcResponse};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::collections::HashMap;
+
+// ============================================================================
+// 请求与响应结构体
+// ============================================================================
+
+/// 超级群频道类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum UltraGroupChannelType {
+    /// 公有频道，所有群成员可见
+    #[default]
+    Public = 0,
+    /// 私有频道，仅频道成员可见
+    Private = 1,
+}
+
+/// 超级群频道信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UltraGroupChannel {
+    /// 频道 ID
+    #[serde(rename = "channelId")]
+    pub channel_id: String,
+    /// 频道类型
+    #[serde(rename = "type", default)]
+    pub channel_type: UltraGroupChannelType,
+    /// 创建时间
+    #[serde(rename = "createTime", default)]
+    pub create_time: Option<String>,
+}
+
+/// 频道列表查询结果
+#[derive(Debug, Deserialize)]
+pub struct UltraGroupChannelList {
+    /// 频道列表
+    #[serde(rename = "channelList", default)]
+    pub channels: Vec<UltraGroupChannel>,
+}
+
+/// 群成员检查结果
+#[derive(Debug, Deserialize)]
+pub struct UltraGroupMemberExist {
+    /// 用户是否为群成员
+    pub status: bool,
+}
+
+/// 超级群用户组
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UltraGroupUserGroup {
+    /// 用户组 ID
+    #[serde(rename = "userGroupId")]
+    pub user_group_id: String,
+}
+
+impl UltraGroupUserGroup {
+    pub fn new(user_group_id: impl Into<String>) -> Self {
+        Self {
+            user_group_id: user_group_id.into(),
+        }
+    }
+}
+
+/// 用户组列表查询结果
+#[derive(Debug, Deserialize)]
+pub struct UltraGroupUserGroupList {
+    /// 用户组列表
+    #[serde(rename = "userGroups", default)]
+    pub user_groups: Vec<UltraGroupUserGroup>,
+}
+
+/// 私有频道成员查询结果
+#[derive(Debug, Deserialize)]
+pub struct UltraGroupChannelUsers {
+    /// 频道成员 ID 列表
+    #[serde(default)]
+    pub users: Vec<String>,
+}
+
+/// 频道 ID 列表查询结果
+///
+/// 用于查询用户所属私有频道、用户组绑定的频道
+#[derive(Debug, Deserialize)]
+pub struct UltraGroupChannelIds {
+    /// 频道 ID 列表
+    #[serde(rename = "data", default)]
+    pub channel_ids: Vec<String>,
+}
+
+/// 用户组 ID 列表查询结果
+///
+/// 用于查询用户所属用户组、频道绑定的用户组
+#[derive(Debug, Deserialize)]
+pub struct UltraGroupUserGroupIds {
+    /// 用户组 ID 列表
+    #[serde(rename = "data", default)]
+    pub user_group_ids: Vec<String>,
+}
+
+/// 超级群禁言成员
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UltraGroupBannedUser {
+    /// 用户 ID
+    #[serde(rename = "id")]
+    pub user_id: String,
+}
+
+/// 超级群禁言成员列表查询结果
+///
+/// 用于成员禁言列表和全体禁言白名单查询
+#[derive(Debug, Deserialize)]
+pub struct UltraGroupBannedUserList {
+    /// 用户列表
+    #[serde(default)]
+    pub users: Vec<UltraGroupBannedUser>,
+}
+
+/// 超级群全体禁言状态
+#[derive(Debug, Deserialize)]
+pub struct UltraGroupGlobalBannedStatus {
+    /// 是否已开启全体禁言
+    pub status: bool,
+}
+
+/// 超级群默认免打扰配置
+#[derive(Debug, Deserialize)]
+pub struct UltraGroupNotDisturb {
+    /// 超级群 ID
+    #[serde(rename = "groupId")]
+    pub group_id: String,
+    /// 频道 ID，群级别配置时为空
+    #[serde(rename = "busChannel", default)]
+    pub bus_channel: Option<String>,
+    /// 免打扰级别
+    #[serde(rename = "unpushLevel")]
+    pub unpush_level: NotificationLevel,
+}
+
+#[derive(Debug, Serialize)]
+struct UltraGroupParams<'a> {
+    #[serde(rename = "groupId")]
+    group_id: &'a str,
+    #[serde(rename = "groupName", skip_serializing_if = "Option::is_none")]
+    group_name: Option<&'a str>,
+    #[serde(rename = "userId", skip_serializing_if = "Option::is_none")]
+    user_id: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize)]
+struct ChannelParams<'a> {
+    #[serde(rename = "groupId")]
+    group_id: &'a str,
+    #[serde(rename = "busChannel")]
+    bus_channel: &'a str,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    channel_type: Option<UltraGroupChannelType>,
+}
+
+#[derive(Debug, Serialize)]
+struct PageQueryParams<'a> {
+    #[serde(rename = "groupId")]
+    group_id: &'a str,
+    #[serde(rename = "busChannel", skip_serializing_if = "Option::is_none")]
+    bus_channel: Option<&'a str>,
+    #[serde(rename = "userId", skip_serializing_if = "Option::is_none")]
+    user_id: Option<&'a str>,
+    #[serde(rename = "userGroupId", skip_serializing_if = "Option::is_none")]
+    user_group_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i32>,
+    #[serde(rename = "pageSize", skip_serializing_if = "Option::is_none")]
+    page_size: Option<i32>,
+}
+
+/// 超级群消息定位信息
+///
+/// 用于批量获取指定超级群消息
+#[derive(Debug, Clone, Serialize)]
+pub struct UltraGroupMessageKey {
+    /// 消息唯一标识
+    #[serde(rename = "msgUID")]
+    pub msg_uid: String,
+    /// 频道 ID
+    #[serde(rename = "busChannel", skip_serializing_if = "Option::is_none")]
+    pub bus_channel: Option<String>,
+}
+
+impl UltraGroupMessageKey {
+    pub fn new(msg_uid: impl Into<String>) -> Self {
+        Self {
+            msg_uid: msg_uid.into(),
+            bus_channel: None,
+        }
+    }
+
+    pub fn bus_channel(mut self, bus_channel: impl Into<String>) -> Self {
+        self.bus_channel = Some(bus_channel.into());
+        self
+    }
+}
+
+#[derive(Deserialize)]
+struct UltraGroupMessageRecordRaw {
+    #[serde(rename = "fromUserId")]
+    from_user_id: String,
+    #[serde(rename = "groupId", default)]
+    group_id: String,
+    #[serde(rename = "busChannel", default)]
+    bus_channel: Option<String>,
+    #[serde(rename = "msgUID")]
+    msg_uid: String,
+    #[serde(rename = "objectName")]
+    object_name: String,
+    #[serde(deserialize_with = "deserialize_json_string")]
+    content: String,
+    #[serde(rename = "sentTime", default)]
+    sent_time: i64,
+    #[serde(default)]
+    expansion: bool,
+}
+
+/// 超级群消息记录
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "UltraGroupMessageRecordRaw")]
+pub struct UltraGroupMessageRecord {
+    /// 发送人 ID
+    pub from_user_id: String,
+    /// 超级群 ID
+    pub group_id: String,
+    /// 频道 ID
+    pub bus_channel: Option<String>,
+    /// 消息唯一标识
+    pub msg_uid: String,
+    /// 按消息类型解析后的消息内容
+    pub content: MessageContent,
+    /// 发送时间（毫秒）
+    pub sent_time: i64,
+    /// 是否支持消息扩展
+    pub expansion: bool,
+}
+
+impl TryFrom<UltraGroupMessageRecordRaw> for UltraGroupMessageRecord {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: UltraGroupMessageRecordRaw) -> Result<Self, Self::Error> {
+        Ok(Self {
+            content: MessageContent::parse(&raw.object_name, &raw.content)?,
+            from_user_id: raw.from_user_id,
+            group_id: raw.group_id,
+            bus_channel: raw.bus_channel,
+            msg_uid: raw.msg_uid,
+            sent_time: raw.sent_time,
+            expansion: raw.expansion,
+        })
+    }
+}
+
+impl UltraGroupMessageRecord {
+    /// 消息类型标识符
+    pub fn object_name(&self) -> &str {
+        self.content.object_name()
+    }
+}
+
+/// 超级群消息列表查询结果
+#[derive(Debug, Deserialize)]
+pub struct UltraGroupMessageList {
+    /// 消息列表
+    #[serde(rename = "data", default)]
+    pub messages: Vec<UltraGroupMessageRecord>,
+}
+
+/// 超级群历史消息搜索条件
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct UltraGroupHistoryQuery {
+    /// 超级群 ID (必填)
+    #[serde(rename = "groupId")]
+    pub group_id: String,
+    /// 频道 ID (可选)
+    #[serde(rename = "busChannel", skip_serializing_if = "Option::is_none")]
+    pub bus_channel: Option<String>,
+    /// 开始时间（毫秒） (可选)
+    #[serde(rename = "startTime", skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// 结束时间（毫秒） (可选)
+    #[serde(rename = "endTime", skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// 发送人 ID (可选)
+    #[serde(rename = "fromUserId", skip_serializing_if = "Option::is_none")]
+    pub from_user_id: Option<String>,
+    /// 每页条数，最大 100 (可选)
+    #[serde(rename = "pageSize", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i32>,
+    /// 结果是否包含开始时间的消息 (可选)
+    #[serde(rename = "includeStart", skip_serializing_if = "Option::is_none")]
+    pub include_start: Option<bool>,
+}
+
+impl UltraGroupHistoryQuery {
+    pub fn new(group_id: impl Into<String>) -> Self {
+        Self {
+            group_id: group_id.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn bus_channel(mut self, bus_channel: impl Into<String>) -> Self {
+        self.bus_channel = Some(bus_channel.into());
+        self
+    }
+
+    pub fn time_range(mut self, start_time: i64, end_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn from_user(mut self, from_user_id: impl Into<String>) -> Self {
+        self.from_user_id = Some(from_user_id.into());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn include_start(mut self, value: bool) -> Self {
+        self.include_start = Some(value);
+        self
+    }
+}
+
+/// 兼容消息内容以 JSON 字符串或 JSON 对象两种形式返回
+fn deserialize_json_string<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) => Ok(s),
+        other => Ok(other.to_string()),
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct BannedParams<'a> {
+    #[serde(rename = "groupId")]
+    group_id: &'a str,
+    #[serde(rename = "userIds", skip_serializing_if = "Option::is_none")]
+    user_ids: Option<String>,
+    #[serde(rename = "busChannel", skip_serializing_if = "Option::is_none")]
+    bus_channel: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+struct NotDisturbParams<'a> {
+    #[serde(rename = "groupId")]
+    group_id: &'a str,
+    #[serde(rename = "busChannel", skip_serializing_if = "Option::is_none")]
+    bus_channel: Option<&'a str>,
+    #[serde(rename = "unpushLevel", skip_serializing_if = "Option::is_none")]
+    unpush_level: Option<NotificationLevel>,
+}
+
+// ============================================================================
+// API 实现
+// ============================================================================
+
+impl RongCloud {
+    /// Create an ultra group.
+    ///
+    /// `user_id` is the creator, who joins the group automatically.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/create
+    pub async fn ultra_group_create(
+        &self,
+        user_id: &str,
+        group_id: &str,
+        group_name: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = UltraGroupParams {
+            group_id,
+            group_name: Some(group_name),
+            user_id: Some(user_id),
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_CREATE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Join an ultra group.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/join
+    pub async fn ultra_group_join(
+        &self,
+        user_id: &str,
+        group_id: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = UltraGroupParams {
+            group_id,
+            group_name: None,
+            user_id: Some(user_id),
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_JOIN,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Quit an ultra group.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/quit
+    pub async fn ultra_group_quit(
+        &self,
+        user_id: &str,
+        group_id: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = UltraGroupParams {
+            group_id,
+            group_name: None,
+            user_id: Some(user_id),
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_QUIT,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Dismiss an ultra group.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/dismiss
+    pub async fn ultra_group_dismiss(
+        &self,
+        group_id: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![("groupId", group_id)];
+        self.post(
+            super::endpoints::ULTRA_GROUP_DISMISS,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Refresh ultra group info.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/refresh
+    pub async fn ultra_group_refresh(
+        &self,
+        group_id: &str,
+        group_name: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = UltraGroupParams {
+            group_id,
+            group_name: Some(group_name),
+            user_id: None,
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_REFRESH,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Check whether a user is a member of an ultra group.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/member/exist
+    pub async fn ultra_group_member_exist(
+        &self,
+        group_id: &str,
+        user_id: &str,
+    ) -> Result<RcResponse<UltraGroupMemberExist>, RongCloudError> {
+        let params = UltraGroupParams {
+            group_id,
+            group_name: None,
+            user_id: Some(user_id),
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_MEMBER_EXIST,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    // ========================================================================
+    // 超级群频道管理
+    // ========================================================================
+
+    /// Create a channel in an ultra group.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/channel/create
+    pub async fn ultra_group_channel_create(
+        &self,
+        group_id: &str,
+        bus_channel: &str,
+        channel_type: UltraGroupChannelType,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = ChannelParams {
+            group_id,
+            bus_channel,
+            channel_type: Some(channel_type),
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_CHANNEL_CREATE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Delete a channel from an ultra group.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/channel/delete
+    pub async fn ultra_group_channel_delete(
+        &self,
+        group_id: &str,
+        bus_channel: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = ChannelParams {
+            group_id,
+            bus_channel,
+            channel_type: None,
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_CHANNEL_DELETE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query channels of an ultra group.
+    ///
+    /// `page` starts from 1, `limit` is at most 100.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/channel/query
+    pub async fn ultra_group_channel_get(
+        &self,
+        group_id: &str,
+        page: Option<i32>,
+        limit: Option<i32>,
+    ) -> Result<RcResponse<UltraGroupChannelList>, RongCloudError> {
+        #[derive(Serialize)]
+        struct QueryParams<'a> {
+            #[serde(rename = "groupId")]
+            group_id: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            page: Option<i32>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            limit: Option<i32>,
+        }
+        let params = QueryParams {
+            group_id,
+            page,
+            limit,
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_CHANNEL_GET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    // ========================================================================
+    // 超级群私有频道
+    // ========================================================================
+
+    /// Change the type of an ultra group channel.
+    ///
+    /// When a private channel becomes public, its member list is cleared.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/channel/type/change
+    pub async fn ultra_group_channel_type_change(
+        &self,
+        group_id: &str,
+        bus_channel: &str,
+        channel_type: UltraGroupChannelType,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = ChannelParams {
+            group_id,
+            bus_channel,
+            channel_type: Some(channel_type),
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_CHANNEL_TYPE_CHANGE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Add members to a private channel.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/channel/private/users/add
+    pub async fn ultra_group_channel_private_users_add(
+        &self,
+        group_id: &str,
+        bus_channel: &str,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("busChannel", bus_channel.to_string()),
+            ("userIds", user_ids.join(",")),
+        ];
+        self.post(
+            super::endpoints::ULTRA_GROUP_CHANNEL_PRIVATE_USERS_ADD,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Remove members from a private channel.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/channel/private/users/delete
+    pub async fn ultra_group_channel_private_users_delete(
+        &self,
+        group_id: &str,
+        bus_channel: &str,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("busChannel", bus_channel.to_string()),
+            ("userIds", user_ids.join(",")),
+        ];
+        self.post(
+            super::endpoints::ULTRA_GROUP_CHANNEL_PRIVATE_USERS_DELETE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query members of a private channel.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/channel/private/users/query
+    pub async fn ultra_group_channel_private_users_get(
+        &self,
+        group_id: &str,
+        bus_channel: &str,
+        page: Option<i32>,
+        page_size: Option<i32>,
+    ) -> Result<RcResponse<UltraGroupChannelUsers>, RongCloudError> {
+        let params = PageQueryParams {
+            group_id,
+            bus_channel: Some(bus_channel),
+            user_id: None,
+            user_group_id: None,
+            page,
+            page_size,
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_CHANNEL_PRIVATE_USERS_GET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query private channels a user belongs to.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/user/channel/query
+    pub async fn ultra_group_user_channel_query(
+        &self,
+        group_id: &str,
+        user_id: &str,
+        page: Option<i32>,
+        page_size: Option<i32>,
+    ) -> Result<RcResponse<UltraGroupChannelIds>, RongCloudError> {
+        let params = PageQueryParams {
+            group_id,
+            bus_channel: None,
+            user_id: Some(user_id),
+            user_group_id: None,
+            page,
+            page_size,
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_USER_CHANNEL_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    // ========================================================================
+    // 超级群用户组
+    // ========================================================================
+
+    /// Create user groups in an ultra group.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/usergroup/add
+    pub async fn ultra_group_user_group_add(
+        &self,
+        group_id: &str,
+        user_groups: Vec<UltraGroupUserGroup>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("userGroups", serde_json::to_string(&user_groups)?),
+        ];
+        self.post(
+            super::endpoints::ULTRA_GROUP_USER_GROUP_ADD,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Delete user groups from an ultra group.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/usergroup/delete
+    pub async fn ultra_group_user_group_delete(
+        &self,
+        group_id: &str,
+        user_group_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("userGroupIds", user_group_ids.join(",")),
+        ];
+        self.post(
+            super::endpoints::ULTRA_GROUP_USER_GROUP_DELETE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query user groups of an ultra group.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/usergroup/query
+    pub async fn ultra_group_user_group_query(
+        &self,
+        group_id: &str,
+        page: Option<i32>,
+        page_size: Option<i32>,
+    ) -> Result<RcResponse<UltraGroupUserGroupList>, RongCloudError> {
+        let params = PageQueryParams {
+            group_id,
+            bus_channel: None,
+            user_id: None,
+            user_group_id: None,
+            page,
+            page_size,
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_USER_GROUP_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Add users to a user group.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/usergroup/user/add
+    pub async fn ultra_group_user_group_user_add(
+        &self,
+        group_id: &str,
+        user_group_id: &str,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("userGroupId", user_group_id.to_string()),
+            ("userIds", user_ids.join(",")),
+        ];
+        self.post(
+            super::endpoints::ULTRA_GROUP_USER_GROUP_USER_ADD,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Remove users from a user group.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/usergroup/user/delete
+    pub async fn ultra_group_user_group_user_delete(
+        &self,
+        group_id: &str,
+        user_group_id: &str,
+        user_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("userGroupId", user_group_id.to_string()),
+            ("userIds", user_ids.join(",")),
+        ];
+        self.post(
+            super::endpoints::ULTRA_GROUP_USER_GROUP_USER_DELETE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query user groups a user belongs to.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/user/usergroup/query
+    pub async fn ultra_group_user_user_group_query(
+        &self,
+        group_id: &str,
+        user_id: &str,
+        page: Option<i32>,
+        page_size: Option<i32>,
+    ) -> Result<RcResponse<UltraGroupUserGroupIds>, RongCloudError> {
+        let params = PageQueryParams {
+            group_id,
+            bus_channel: None,
+            user_id: Some(user_id),
+            user_group_id: None,
+            page,
+            page_size,
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_USER_USER_GROUP_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Bind user groups to a channel.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/channel/usergroup/bind
+    pub async fn ultra_group_channel_user_group_bind(
+        &self,
+        group_id: &str,
+        bus_channel: &str,
+        user_group_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("busChannel", bus_channel.to_string()),
+            ("userGroupIds", user_group_ids.join(",")),
+        ];
+        self.post(
+            super::endpoints::ULTRA_GROUP_CHANNEL_USER_GROUP_BIND,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Unbind user groups from a channel.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/channel/usergroup/unbind
+    pub async fn ultra_group_channel_user_group_unbind(
+        &self,
+        group_id: &str,
+        bus_channel: &str,
+        user_group_ids: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("busChannel", bus_channel.to_string()),
+            ("userGroupIds", user_group_ids.join(",")),
+        ];
+        self.post(
+            super::endpoints::ULTRA_GROUP_CHANNEL_USER_GROUP_UNBIND,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query user groups bound to a channel.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/channel/usergroup/query
+    pub async fn ultra_group_channel_user_group_query(
+        &self,
+        group_id: &str,
+        bus_channel: &str,
+        page: Option<i32>,
+        page_size: Option<i32>,
+    ) -> Result<RcResponse<UltraGroupUserGroupIds>, RongCloudError> {
+        let params = PageQueryParams {
+            group_id,
+            bus_channel: Some(bus_channel),
+            user_id: None,
+            user_group_id: None,
+            page,
+            page_size,
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_CHANNEL_USER_GROUP_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query channels bound to a user group.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/usergroup/channel/query
+    pub async fn ultra_group_user_group_channel_query(
+        &self,
+        group_id: &str,
+        user_group_id: &str,
+        page: Option<i32>,
+        page_size: Option<i32>,
+    ) -> Result<RcResponse<UltraGroupChannelIds>, RongCloudError> {
+        let params = PageQueryParams {
+            group_id,
+            bus_channel: None,
+            user_id: None,
+            user_group_id: Some(user_group_id),
+            page,
+            page_size,
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_USER_GROUP_CHANNEL_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    // ========================================================================
+    // 超级群禁言服务
+    // ========================================================================
+
+    /// Ban ultra group members.
+    ///
+    /// If `bus_channel` is `None`, members are banned in the whole group.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/userbanned/add
+    pub async fn ultra_group_user_banned_add(
+        &self,
+        group_id: &str,
+        user_ids: Vec<&str>,
+        bus_channel: Option<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = BannedParams {
+            group_id,
+            user_ids: Some(user_ids.join(",")),
+            bus_channel,
+            status: None,
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_USER_BANNED_ADD,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Unban ultra group members.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/userbanned/delete
+    pub async fn ultra_group_user_banned_rollback(
+        &self,
+        group_id: &str,
+        user_ids: Vec<&str>,
+        bus_channel: Option<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = BannedParams {
+            group_id,
+            user_ids: Some(user_ids.join(",")),
+            bus_channel,
+            status: None,
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_USER_BANNED_DELETE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// List banned ultra group members.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/userbanned/query
+    pub async fn ultra_group_user_banned_list(
+        &self,
+        group_id: &str,
+        bus_channel: Option<&str>,
+        page: Option<i32>,
+        page_size: Option<i32>,
+    ) -> Result<RcResponse<UltraGroupBannedUserList>, RongCloudError> {
+        let params = PageQueryParams {
+            group_id,
+            bus_channel,
+            user_id: None,
+            user_group_id: None,
+            page,
+            page_size,
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_USER_BANNED_GET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Set or cancel ban on an entire ultra group (全体禁言).
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/globalbanned/set
+    pub async fn ultra_group_global_banned_set(
+        &self,
+        group_id: &str,
+        bus_channel: Option<&str>,
+        status: bool,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = BannedParams {
+            group_id,
+            user_ids: None,
+            bus_channel,
+            status: Some(status),
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_GLOBAL_BANNED_SET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query ultra group ban status.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/globalbanned/query
+    pub async fn ultra_group_global_banned_get(
+        &self,
+        group_id: &str,
+        bus_channel: Option<&str>,
+    ) -> Result<RcResponse<UltraGroupGlobalBannedStatus>, RongCloudError> {
+        let params = BannedParams {
+            group_id,
+            user_ids: None,
+            bus_channel,
+            status: None,
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_GLOBAL_BANNED_GET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Add users to ultra group ban whitelist.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/banned/whitelist/add
+    pub async fn ultra_group_banned_whitelist_add(
+        &self,
+        group_id: &str,
+        user_ids: Vec<&str>,
+        bus_channel: Option<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = BannedParams {
+            group_id,
+            user_ids: Some(user_ids.join(",")),
+            bus_channel,
+            status: None,
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_BANNED_WHITELIST_ADD,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Remove users from ultra group ban whitelist.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/banned/whitelist/delete
+    pub async fn ultra_group_banned_whitelist_rollback(
+        &self,
+        group_id: &str,
+        user_ids: Vec<&str>,
+        bus_channel: Option<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = BannedParams {
+            group_id,
+            user_ids: Some(user_ids.join(",")),
+            bus_channel,
+            status: None,
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_BANNED_WHITELIST_DELETE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query ultra group ban whitelist.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/banned/whitelist/query
+    pub async fn ultra_group_banned_whitelist_list(
+        &self,
+        group_id: &str,
+        bus_channel: Option<&str>,
+        page: Option<i32>,
+        page_size: Option<i32>,
+    ) -> Result<RcResponse<UltraGroupBannedUserList>, RongCloudError> {
+        let params = PageQueryParams {
+            group_id,
+            bus_channel,
+            user_id: None,
+            user_group_id: None,
+            page,
+            page_size,
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_BANNED_WHITELIST_GET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    // ========================================================================
+    // 超级群默认免打扰
+    // ========================================================================
+
+    /// Set default do-not-disturb level of an ultra group or channel.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/notdisturb/set
+    pub async fn ultra_group_not_disturb_set(
+        &self,
+        group_id: &str,
+        bus_channel: Option<&str>,
+        level: NotificationLevel,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = NotDisturbParams {
+            group_id,
+            bus_channel,
+            unpush_level: Some(level),
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_NOT_DISTURB_SET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query default do-not-disturb level of an ultra group or channel.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/notdisturb/query
+    pub async fn ultra_group_not_disturb_get(
+        &self,
+        group_id: &str,
+        bus_channel: Option<&str>,
+    ) -> Result<RcResponse<UltraGroupNotDisturb>, RongCloudError> {
+        let params = NotDisturbParams {
+            group_id,
+            bus_channel,
+            unpush_level: None,
+        };
+        self.post(
+            super::endpoints::ULTRA_GROUP_NOT_DISTURB_GET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    // ========================================================================
+    // 超级群消息
+    // ========================================================================
+
+    /// Get ultra group messages by `msgUID`, at most 20 per call.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/msg/get
+    pub async fn ultra_group_msg_get(
+        &self,
+        group_id: &str,
+        msgs: Vec<UltraGroupMessageKey>,
+    ) -> Result<RcResponse<UltraGroupMessageList>, RongCloudError> {
+        let params = vec![
+            ("groupId", group_id.to_string()),
+            ("msgs", serde_json::to_string(&msgs)?),
+        ];
+        self.post(
+            super::endpoints::ULTRA_GROUP_MSG_GET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Modify the content of an ultra group message.
+    ///
+    /// The new content must have the same message type as the original one.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/msg/modify
+    pub async fn ultra_group_msg_modify<M: Message>(
+        &self,
+        group_id: &str,
+        from_user_id: &str,
+        msg_uid: &str,
+        bus_channel: Option<&str>,
+        content: &M,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let mut params = vec![
+            ("groupId", group_id.to_string()),
+            ("fromUserId", from_user_id.to_string()),
+            ("msgUID", msg_uid.to_string()),
+            ("content", content.to_json()?),
+        ];
+        if let Some(channel) = bus_channel {
+            params.push(("busChannel", channel.to_string()));
+        }
+        self.post(
+            super::endpoints::ULTRA_GROUP_MSG_MODIFY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Search ultra group message history.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/hismsg/query
+    pub async fn ultra_group_his_msg_query(
+        &self,
+        query: &UltraGroupHistoryQuery,
+    ) -> Result<RcResponse<UltraGroupMessageList>, RongCloudError> {
+        self.post(
+            super::endpoints::ULTRA_GROUP_HIS_MSG_QUERY,
+            query,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Set expansion key/values of an ultra group message.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/message/expansion/set
+    pub async fn ultra_group_message_expansion_set(
+        &self,
+        group_id: &str,
+        msg_uid: &str,
+        user_id: &str,
+        bus_channel: Option<&str>,
+        extra: &HashMap<String, String>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        validate_expansion_entries(extra)?;
+        let mut params = vec![
+            ("groupId", group_id.to_string()),
+            ("msgUID", msg_uid.to_string()),
+            ("userId", user_id.to_string()),
+            ("extraKeyVal", serde_json::to_string(extra)?),
+        ];
+        if let Some(channel) = bus_channel {
+            params.push(("busChannel", channel.to_string()));
+        }
+        self.post(
+            super::endpoints::ULTRA_GROUP_MESSAGE_EXPANSION_SET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Delete expansion keys of an ultra group message.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/message/expansion/delete
+    pub async fn ultra_group_message_expansion_delete(
+        &self,
+        group_id: &str,
+        msg_uid: &str,
+        user_id: &str,
+        bus_channel: Option<&str>,
+        keys: Vec<&str>,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        validate_expansion_keys(keys.iter().copied())?;
+        let mut params = vec![
+            ("groupId", group_id.to_string()),
+            ("msgUID", msg_uid.to_string()),
+            ("userId", user_id.to_string()),
+            ("extraKey", serde_json::to_string(&keys)?),
+        ];
+        if let Some(channel) = bus_channel {
+            params.push(("busChannel", channel.to_string()));
+        }
+        self.post(
+            super::endpoints::ULTRA_GROUP_MESSAGE_EXPANSION_DELETE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query expansion key/values of an ultra group message.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/ultragroup/message/expansion/query
+    pub async fn ultra_group_message_expansion_query(
+        &self,
+        group_id: &str,
+        msg_uid: &str,
+        bus_channel: Option<&str>,
+    ) -> Result<RcResponse<MessageExpansion>, RongCloudError> {
+        let mut params = vec![
+            ("groupId", group_id.to_string()),
+            ("msgUID", msg_uid.to_string()),
+        ];
+        if let Some(channel) = bus_channel {
+            params.push(("busChannel", channel.to_string()));
+        }
+        self.post(
+            super::endpoints::ULTRA_GROUP_MESSAGE_EXPANSION_QUERY,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::message::{EXPANSION_KEY_MAX_LEN, EXPANSION_VALUE_MAX_LEN, UltraGroupMessage};
+    use crate::core::RongCloudConfig;
+    use crate::messages::TxtMessage;
+    use mockito;
+
+    #[tokio::test]
+    async fn test_ultra_group_ops() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_create = server
+            .mock("POST", "/ultragroup/create.json")
+            .match_body("groupId=ug1&groupName=room&userId=u1")
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+        let result = client.ultra_group_create("u1", "ug1", "room").await;
+        mock_create.assert_async().await;
+        assert!(result.unwrap().is_success());
+
+        let mock_exist = server
+            .mock("POST", "/ultragroup/member/exist.json")
+            .with_status(200)
+            .with_body(r#"{"code": 200, "status": true}"#)
+            .create_async()
+            .await;
+        let result = client.ultra_group_member_exist("ug1", "u1").await;
+        mock_exist.assert_async().await;
+        assert!(result.unwrap().data.unwrap().status);
+    }
+
+    #[tokio::test]
+    async fn test_ultra_group_channel_get() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock = server
+            .mock("POST", "/ultragroup/channel/get.json")
+            .with_status(200)
+            .with_body(
+                r#"{"code": 200, "channelList": [{"channelId": "c1", "type": 1, "createTime": "2024-01-01 00:00:00"}]}"#,
+            )
+            .create_async()
+            .await;
+        let result = client
+            .ultra_group_channel_get("ug1", Some(1), Some(20))
+            .await;
+        mock.assert_async().await;
+
+        let channels = result.unwrap().data.unwrap().channels;
+        assert_eq!(channels.len(), 1);
+        assert_eq!(channels[0].channel_id, "c1");
+        assert_eq!(channels[0].channel_type, UltraGroupChannelType::Private);
+    }
+
+    #[tokio::test]
+    async fn test_ultra_group_user_group_ops() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_add = server
+            .mock("POST", "/ultragroup/usergroup/add.json")
+            .match_body(mockito::Matcher::UrlEncoded(
+                "userGroups".into(),
+                r#"[{"userGroupId":"g1"}]"#.into(),
+            ))
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+        let result = client
+            .ultra_group_user_group_add("ug1", vec![UltraGroupUserGroup::new("g1")])
+            .await;
+        mock_add.assert_async().await;
+        assert!(result.is_ok());
+
+        let mock_query = server
+            .mock("POST", "/ultragroup/channel/usergroup/query.json")
+            .with_status(200)
+            .with_body(r#"{"code": 200, "data": ["g1", "g2"]}"#)
+            .create_async()
+            .await;
+        let result = client
+            .ultra_group_channel_user_group_query("ug1", "c1", None, None)
+            .await;
+        mock_query.assert_async().await;
+        assert_eq!(
+            result.unwrap().data.unwrap().user_group_ids,
+            vec!["g1", "g2"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ultra_group_banned_ops() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_add = server
+            .mock("POST", "/ultragroup/userbanned/add.json")
+            .match_body("groupId=ug1&userIds=u1%2Cu2&busChannel=c1")
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+        let result = client
+            .ultra_group_user_banned_add("ug1", vec!["u1", "u2"], Some("c1"))
+            .await;
+        mock_add.assert_async().await;
+        assert!(result.is_ok());
+
+        let mock_rollback = server
+            .mock("POST", "/ultragroup/userbanned/del.json")
+            .match_body("groupId=ug1&userIds=u1&busChannel=c1")
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+        let result = client
+            .ultra_group_user_banned_rollback("ug1", vec!["u1"], Some("c1"))
+            .await;
+        mock_rollback.assert_async().await;
+        assert!(result.is_ok());
+
+        let mock_list = server
+            .mock("POST", "/ultragroup/userbanned/get.json")
+            .match_body("groupId=ug1&page=1&pageSize=50")
+            .with_status(200)
+            .with_body(r#"{"code": 200, "users": [{"id": "u2"}]}"#)
+            .create_async()
+            .await;
+        let result = client
+            .ultra_group_user_banned_list("ug1", None, Some(1), Some(50))
+            .await;
+        mock_list.assert_async().await;
+        assert_eq!(result.unwrap().data.unwrap().users[0].user_id, "u2");
+
+        let mock_set = server
+            .mock("POST", "/ultragroup/notdisturb/set.json")
+            .match_body("groupId=ug1&busChannel=c1&unpushLevel=1")
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+        let result = client
+            .ultra_group_not_disturb_set("ug1", Some("c1"), NotificationLevel::Mention)
+            .await;
+        mock_set.assert_async().await;
+        assert!(result.is_ok());
+
+        let mock_get = server
+            .mock("POST", "/ultragroup/notdisturb/get.json")
+            .with_status(200)
+            .with_body(r#"{"code": 200, "groupId": "ug1", "unpushLevel": -1}"#)
+            .create_async()
+            .await;
+        let result = client.ultra_group_not_disturb_get("ug1", None).await;
+        mock_get.assert_async().await;
+        let data = result.unwrap().data.unwrap();
+        assert_eq!(data.unpush_level, NotificationLevel::All);
+        assert!(data.bus_channel.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_ultra_group_message_ops() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let text = TxtMessage::new("hello");
+        let msg = UltraGroupMessage::from_message("u1", &text)
+            .unwrap()
+            .to_group("ug1")
+            .bus_channel("c1");
+        let mock_send = server
+            .mock("POST", "/message/ultragroup/publish.json")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"toGroupIds": ["ug1"], "busChannel": "c1", "objectName": "RC:TxtMsg"}"#
+                    .to_string(),
+            ))
+            .with_status(200)
+            .with_body(r#"{"code": 200, "messageUID": "MSG-1"}"#)
+            .create_async()
+            .await;
+        let result = client.send_ultra_group_message(&msg).await;
+        mock_send.assert_async().await;
+        assert_eq!(result.unwrap().data.unwrap().message_uid, "MSG-1");
+
+        let mock_query = server
+            .mock("POST", "/ultragroup/hismsg/query.json")
+            .with_status(200)
+            .with_body(
+                r#"{"code": 200, "data": [{"fromUserId": "u1", "groupId": "ug1", "busChannel": "c1", "msgUID": "MSG-1", "objectName": "RC:TxtMsg", "content": {"content": "hello"}, "sentTime": 1700000000000}]}"#,
+            )
+            .create_async()
+            .await;
+        let query = UltraGroupHistoryQuery::new("ug1")
+            .bus_channel("c1")
+            .page_size(50);
+        let result = client.ultra_group_his_msg_query(&query).await;
+        mock_query.assert_async().await;
+
+        let messages = result.unwrap().data.unwrap().messages;
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].object_name(), "RC:TxtMsg");
+        assert!(
+            matches!(&messages[0].content, MessageContent::Txt(text) if text.content == "hello")
+        );
+
+        let mock_get = server
+            .mock("POST", "/ultragroup/msg/get.json")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("groupId".into(), "ug1".into()),
+                mockito::Matcher::UrlEncoded(
+                    "msgs".into(),
+                    r#"[{"msgUID":"MSG-1","busChannel":"c1"},{"msgUID":"MSG-2"}]"#.into(),
+                ),
+            ]))
+            .with_status(200)
+            .with_body(
+                r#"{"code": 200, "data": [{"fromUserId": "u1", "groupId": "ug1", "msgUID": "MSG-2", "objectName": "App:Custom", "content": "{\"k\":1}"}]}"#,
+            )
+            .create_async()
+            .await;
+        let keys = vec![
+            UltraGroupMessageKey::new("MSG-1").bus_channel("c1"),
+            UltraGroupMessageKey::new("MSG-2"),
+        ];
+        let result = client.ultra_group_msg_get("ug1", keys).await;
+        mock_get.assert_async().await;
+        let messages = result.unwrap().data.unwrap().messages;
+        assert_eq!(messages[0].object_name(), "App:Custom");
+        assert!(
+            matches!(&messages[0].content, MessageContent::Custom { content, .. } if content == r#"{"k":1}"#)
+        );
+
+        let mock_modify = server
+            .mock("POST", "/ultragroup/msg/modify.json")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("groupId".into(), "ug1".into()),
+                mockito::Matcher::UrlEncoded("fromUserId".into(), "u1".into()),
+                mockito::Matcher::UrlEncoded("msgUID".into(), "MSG-1".into()),
+                mockito::Matcher::UrlEncoded("busChannel".into(), "c1".into()),
+                mockito::Matcher::UrlEncoded("content".into(), r#"{"content":"edited"}"#.into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+        let result = client
+            .ultra_group_msg_modify("ug1", "u1", "MSG-1", Some("c1"), &TxtMessage::new("edited"))
+            .await;
+        mock_modify.assert_async().await;
+        assert!(result.unwrap().is_success());
+    }
+
+    #[tokio::test]
+    async fn test_ultra_group_message_expansion_validation() {
+        let client = RongCloud::new(RongCloudConfig::new("app_key", "app_secret"));
+
+        let long_key = "k".repeat(EXPANSION_KEY_MAX_LEN + 1);
+        let mut extra = HashMap::new();
+        extra.insert(long_key.clone(), "v".to_string());
+        let result = client
+            .ultra_group_message_expansion_set("ug1", "MSG-1", "u1", None, &extra)
+            .await;
+        assert!(matches!(result, Err(RongCloudError::InvalidParameter(_))));
+
+        let mut extra = HashMap::new();
+        extra.insert("k".to_string(), "v".repeat(EXPANSION_VALUE_MAX_LEN + 1));
+        let result = client
+            .ultra_group_message_expansion_set("ug1", "MSG-1", "u1", None, &extra)
+            .await;
+        assert!(matches!(result, Err(RongCloudError::InvalidParameter(_))));
+
+        let result = client
+            .ultra_group_message_expansion_delete("ug1", "MSG-1", "u1", None, vec![])
+            .await;
+        assert!(matches!(result, Err(RongCloudError::InvalidParameter(_))));
+
+        let result = client
+            .ultra_group_message_expansion_delete(
+                "ug1",
+                "MSG-1",
+                "u1",
+                None,
+                vec![long_key.as_str()],
+            )
+            .await;
+        assert!(matches!(result, Err(RongCloudError::InvalidParameter(_))));
+    }
+}
@@ -1,7 +1,9 @@
 use crate::core::RongCloud;
 use crate::core::RongCloudError;
-use crate::types::RcResponse;
-use serde::{Deserialize, Serialize};
+use crate::types::{NotificationLevel, RcResponse};
+use crate::util::deserialize_embedded_json;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, Serialize)]
 pub struct UserRegisterParams<'a> {
@@ -58,6 +60,253 @@ pub struct BlockList {
     pub users: Option<Vec<BlockUser>>, // API might return null if empty
 }
 
+/// 用户标签查询结果
+#[derive(Debug, Deserialize)]
+pub struct UserTagsResult {
+    /// 用户 ID 到标签列表的映射
+    #[serde(default)]
+    pub result: HashMap<String, Vec<String>>,
+}
+
+/// 用户 ID 列表（注销用户、白名单等查询结果）
+#[derive(Debug, Deserialize)]
+pub struct UserIdList {
+    /// 用户 ID 列表
+    #[serde(default)]
+    pub users: Vec<String>,
+}
+
+/// 单聊禁言用户查询结果
+#[derive(Debug, Deserialize)]
+pub struct UserChatBanList {
+    /// 禁言用户总数
+    #[serde(default)]
+    pub total: i64,
+    /// 禁言用户 ID 列表
+    #[serde(default)]
+    pub users: Vec<String>,
+}
+
+/// 用户白名单服务状态
+#[derive(Debug, Deserialize)]
+pub struct UserWhiteSetting {
+    /// 1 为已开启，0 为未开启
+    #[serde(default)]
+    pub status: i32,
+}
+
+/// 托管用户资料
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserProfile {
+    /// 用户 ID
+    #[serde(rename = "userId")]
+    pub user_id: String,
+    /// 用户基础资料（昵称、头像等）
+    #[serde(
+        rename = "userProfile",
+        default,
+        deserialize_with = "deserialize_embedded_json"
+    )]
+    pub profile: Option<HashMap<String, serde_json::Value>>,
+    /// 用户扩展资料
+    #[serde(
+        rename = "userExtProfile",
+        default,
+        deserialize_with = "deserialize_embedded_json"
+    )]
+    pub ext_profile: Option<HashMap<String, serde_json::Value>>,
+    /// 资料版本号
+    #[serde(default)]
+    pub version: Option<i64>,
+}
+
+/// 托管用户资料查询结果
+#[derive(Debug, Deserialize)]
+pub struct UserProfileList {
+    /// 用户资料列表
+    #[serde(rename = "userList", default)]
+    pub users: Vec<UserProfile>,
+}
+
+/// 免打扰时段最大时长（分钟）
+pub const QUIET_PERIOD_MAX_MINUTES: u32 = 1440;
+
+/// 用户免打扰时段
+///
+/// 从每天的 `start_time` 开始，持续 `period` 分钟
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct QuietPeriod {
+    /// 开始时间，格式 `HH:MM:SS`
+    #[serde(rename = "startTime", default)]
+    pub start_time: String,
+    /// 持续时长（分钟）
+    #[serde(default, deserialize_with = "deserialize_period")]
+    pub period: u32,
+    /// 免打扰级别，仅支持 `Mention` 与 `Blocked`，未设置时为 `Blocked`
+    #[serde(rename = "unPushLevel", default)]
+    pub level: Option<NotificationLevel>,
+}
+
+impl QuietPeriod {
+    /// 创建并校验免打扰时段
+    pub fn new(start_time: impl Into<String>, period: u32) -> Result<Self, RongCloudError> {
+        let quiet_period = Self {
+            start_time: start_time.into(),
+            period,
+            level: None,
+        };
+        quiet_period.validate()?;
+        Ok(quiet_period)
+    }
+
+    /// 设置免打扰级别，仅支持 `Mention` 与 `Blocked`
+    pub fn level(mut self, level: NotificationLevel) -> Result<Self, RongCloudError> {
+        validate_quiet_level(level)?;
+        self.level = Some(level);
+        Ok(self)
+    }
+
+    /// 校验开始时间格式、时长范围及免打扰级别
+    pub fn validate(&self) -> Result<(), RongCloudError> {
+        let parts: Vec<&str> = self.start_time.split(':').collect();
+        let valid_time = parts.len() == 3
+            && parts
+                .iter()
+                .all(|p| p.len() == 2 && p.bytes().all(|b| b.is_ascii_digit()))
+            && parts
+                .iter()
+                .zip([24, 60, 60])
+                .all(|(p, max)| p.parse::<u32>().is_ok_and(|v| v < max));
+        if !valid_time {
+            return Err(RongCloudError::InvalidParameter(format!(
+                "start time `{}` must be in HH:MM:SS format",
+                self.start_time
+            )));
+        }
+        if self.period == 0 || self.period > QUIET_PERIOD_MAX_MINUTES {
+            return Err(RongCloudError::InvalidParameter(format!(
+                "period must be between 1 and {} minutes",
+                QUIET_PERIOD_MAX_MINUTES
+            )));
+        }
+        if let Some(level) = self.level {
+            validate_quiet_level(level)?;
+        }
+        Ok(())
+    }
+}
+
+fn validate_quiet_level(level: NotificationLevel) -> Result<(), RongCloudError> {
+    match level {
+        NotificationLevel::Mention | NotificationLevel::Blocked => Ok(()),
+        other => Err(RongCloudError::InvalidParameter(format!(
+            "quiet period does not support level `{}`",
+            other.description()
+        ))),
+    }
+}
+
+/// 免打扰时段查询结果
+#[derive(Debug, Clone, Deserialize)]
+pub struct QuietPeriodResult {
+    /// 未设置免打扰时段时为空
+    #[serde(default)]
+    pub data: Option<QuietPeriod>,
+}
+
+/// 时长可能以数字或数字字符串返回
+fn deserialize_period<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    Ok(deserialize_embedded_json::<D, u32>(deserializer)?.unwrap_or_default())
+}
+
+/// 批量设置标签时单次调用的最大用户数
+pub const USER_TAG_BATCH_MAX_USERS: usize = 1000;
+/// 单个用户的最大标签数
+pub const USER_TAGS_MAX: usize = 20;
+
+/// 批量设置标签的调用间隔（频率限制 10 次/每秒）
+const USER_TAG_BATCH_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// 批量设置标签中单次调用的结果
+#[derive(Debug)]
+pub struct UserTagBatchChunk {
+    /// 分片序号，从 0 开始
+    pub index: usize,
+    /// 本次调用涉及的用户 ID
+    pub user_ids: Vec<String>,
+    /// 调用结果，业务错误码以 `RongCloudError::Api` 表示
+    pub outcome: Result<(), RongCloudError>,
+}
+
+/// 批量设置标签的分片结果汇总
+#[derive(Debug, Default)]
+pub struct UserTagBatchReport {
+    /// 各分片结果，按调用顺序排列
+    pub chunks: Vec<UserTagBatchChunk>,
+}
+
+impl UserTagBatchReport {
+    /// 是否全部分片成功
+    pub fn is_success(&self) -> bool {
+        self.chunks.iter().all(|c| c.outcome.is_ok())
+    }
+
+    /// 失败的分片，可据此重试
+    pub fn failed_chunks(&self) -> impl Iterator<Item = &UserTagBatchChunk> {
+        self.chunks.iter().filter(|c| c.outcome.is_err())
+    }
+
+    /// 成功设置标签的用户数
+    pub fn succeeded_user_count(&self) -> usize {
+        self.chunks
+            .iter()
+            .filter(|c| c.outcome.is_ok())
+            .map(|c| c.user_ids.len())
+            .sum()
+    }
+}
+
+/// 单次设置推送备注名的最大数量
+pub const REMARKS_MAX_PER_CALL: usize = 100;
+
+/// 推送备注名
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PushRemark {
+    /// 被备注的用户 ID
+    pub id: String,
+    /// 备注名
+    pub remark: String,
+}
+
+/// 推送备注名分页查询结果
+#[derive(Debug, Clone, Deserialize)]
+pub struct PushRemarkList {
+    /// 备注总数
+    #[serde(default)]
+    pub total: i32,
+    /// 当前页的备注名
+    #[serde(rename = "users", default)]
+    pub remarks: Vec<PushRemark>,
+}
+
+/// 将 `(目标用户 ID, 备注名)` 列表校验并序列化为 `remarks` 参数
+pub(crate) fn remarks_param(remarks: &[(&str, &str)]) -> Result<String, RongCloudError> {
+    if remarks.is_empty() || remarks.len() > REMARKS_MAX_PER_CALL {
+        return Err(RongCloudError::InvalidParameter(format!(
+            "remarks must contain between 1 and {} entries",
+            REMARKS_MAX_PER_CALL
+        )));
+    }
+    let remarks: Vec<PushRemark> = remarks
+        .iter()
+        .map(|(id, remark)| PushRemark {
+            id: id.to_string(),
+            remark: remark.to_string(),
+        })
+        .collect();
+    Ok(serde_json::to_string(&remarks)?)
+}
+
 impl RongCloud {
     /// Register a user and get a token.
     ///
@@ -307,18 +556,80 @@ impl RongCloud {
             .await
     }
 
+    /// Apply the same tags to many users.
+    ///
+    /// Users are split into chunks of `USER_TAG_BATCH_MAX_USERS`, and calls are
+    /// paced to stay under 10 requests per second. A failed chunk does not
+    /// stop the remaining ones; check the returned report for per-chunk
+    /// outcomes.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/user/tag/batch/set
+    pub async fn user_tag_batch_set(
+        &self,
+        user_ids: &[&str],
+        tags: &[&str],
+    ) -> Result<UserTagBatchReport, RongCloudError> {
+        if user_ids.is_empty() {
+            return Err(RongCloudError::InvalidParameter(
+                "user_ids must not be empty".to_string(),
+            ));
+        }
+        if tags.len() > USER_TAGS_MAX {
+            return Err(RongCloudError::InvalidParameter(format!(
+                "a user can have at most {} tags",
+                USER_TAGS_MAX
+            )));
+        }
+
+        #[derive(Serialize)]
+        struct TagBatchSetParams<'a> {
+            #[serde(rename = "userIds")]
+            user_ids: &'a [&'a str],
+            tags: &'a [&'a str],
+        }
+
+        let mut report = UserTagBatchReport::default();
+        for (index, chunk) in user_ids.chunks(USER_TAG_BATCH_MAX_USERS).enumerate() {
+            if index > 0 {
+                tokio::time::sleep(USER_TAG_BATCH_INTERVAL).await;
+            }
+            let params = TagBatchSetParams {
+                user_ids: chunk,
+                tags,
+            };
+            let outcome = self
+                .post::<RcResponse<()>, _>(
+                    super::endpoints::USER_TAG_BATCH_SET,
+                    &params,
+                    "application/json",
+                )
+                .await
+                .and_then(|response| {
+                    if response.is_success() {
+                        Ok(())
+                    } else {
+                        Err(RongCloudError::Api {
+                            code: response.code,
+                            msg: response.msg,
+                        })
+                    }
+                });
+            report.chunks.push(UserTagBatchChunk {
+                index,
+                user_ids: chunk.iter().map(|id| id.to_string()).collect(),
+                outcome,
+            });
+        }
+        Ok(report)
+    }
+
     /// Get tags for users.
     ///
     /// See: https://doc.rongcloud.cn/imserver/server/v1/user/tags/get
     pub async fn user_tags_get(
         &self,
         user_ids: Vec<&str>,
-    ) -> Result<RcResponse<()>, RongCloudError> {
-        // Returns complex structure, using unit for now or need generic maps
-        // Java SDK: GetTagResult -> result (Map<String, Vec<String>>)
-        // Since I'm using RcResponse, data should be the map?
-        // Let's use serde_json::Value for data to be safe or implement specific struct
-
+    ) -> Result<RcResponse<UserTagsResult>, RongCloudError> {
         // Params needs to be x-www-form-urlencoded with multiple userIds
         // generic params struct with manual serialization or using vec
         let params: Vec<(&str, &str)> = user_ids.iter().map(|id| ("userIds", *id)).collect();
@@ -365,7 +676,7 @@ impl RongCloud {
         typ: &str,
         num: Option<i32>,
         offset: Option<i32>,
-    ) -> Result<RcResponse<()>, RongCloudError> {
+    ) -> Result<RcResponse<UserChatBanList>, RongCloudError> {
         #[derive(Serialize)]
         struct BanQueryParams<'a> {
             #[serde(rename = "type")]
@@ -442,7 +753,7 @@ impl RongCloud {
         &self,
         page_num: Option<i32>,
         page_size: Option<i32>,
-    ) -> Result<RcResponse<()>, RongCloudError> {
+    ) -> Result<RcResponse<UserIdList>, RongCloudError> {
         #[derive(Serialize)]
         struct QueryParams {
             #[serde(rename = "pageNum", skip_serializing_if = "Option::is_none")]
@@ -514,7 +825,7 @@ impl RongCloud {
     pub async fn user_whitesetting_query(
         &self,
         user_id: &str,
-    ) -> Result<RcResponse<()>, RongCloudError> {
+    ) -> Result<RcResponse<UserWhiteSetting>, RongCloudError> {
         #[derive(Serialize)]
         struct QueryParams<'a> {
             #[serde(rename = "userId")]
@@ -578,7 +889,7 @@ impl RongCloud {
     pub async fn user_whitelist_query(
         &self,
         user_id: &str,
-    ) -> Result<RcResponse<()>, RongCloudError> {
+    ) -> Result<RcResponse<UserIdList>, RongCloudError> {
         #[derive(Serialize)]
         struct QueryParams<'a> {
             #[serde(rename = "userId")]
@@ -651,7 +962,7 @@ impl RongCloud {
     pub async fn user_profile_batch_query(
         &self,
         user_ids: Vec<&str>,
-    ) -> Result<RcResponse<()>, RongCloudError> {
+    ) -> Result<RcResponse<UserProfileList>, RongCloudError> {
         let params: Vec<(&str, &str)> = user_ids.iter().map(|id| ("userId", *id)).collect();
 
         self.post(
@@ -669,7 +980,7 @@ impl RongCloud {
         &self,
         page: Option<i32>,
         size: Option<i32>,
-    ) -> Result<RcResponse<()>, RongCloudError> {
+    ) -> Result<RcResponse<UserProfileList>, RongCloudError> {
         #[derive(Serialize)]
         struct QueryParams {
             #[serde(skip_serializing_if = "Option::is_none")]
@@ -686,6 +997,126 @@ impl RongCloud {
         )
         .await
     }
+
+    /// Set the daily do-not-disturb period of a user.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/user/set-quiet-hours
+    pub async fn user_block_push_period_set(
+        &self,
+        user_id: &str,
+        quiet_period: &QuietPeriod,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        quiet_period.validate()?;
+        let mut params = vec![
+            ("userId", user_id.to_string()),
+            ("startTime", quiet_period.start_time.clone()),
+            ("period", quiet_period.period.to_string()),
+        ];
+        if let Some(level) = quiet_period.level {
+            params.push(("level", (level as i8).to_string()));
+        }
+        self.post(
+            super::endpoints::USER_BLOCK_PUSH_PERIOD_SET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Delete the do-not-disturb period of a user.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/user/delete-quiet-hours
+    pub async fn user_block_push_period_delete(
+        &self,
+        user_id: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = [("userId", user_id)];
+        self.post(
+            super::endpoints::USER_BLOCK_PUSH_PERIOD_DELETE,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Get the do-not-disturb period of a user.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/user/get-quiet-hours
+    pub async fn user_block_push_period_get(
+        &self,
+        user_id: &str,
+    ) -> Result<RcResponse<QuietPeriodResult>, RongCloudError> {
+        let params = [("userId", user_id)];
+        self.post(
+            super::endpoints::USER_BLOCK_PUSH_PERIOD_GET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Set push remark names that a user assigned to other users.
+    ///
+    /// `remarks` holds `(target_user_id, remark)` pairs, at most 100 per call.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/user/set-remark-for-push
+    pub async fn user_remarks_set(
+        &self,
+        user_id: &str,
+        remarks: &[(&str, &str)],
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = [
+            ("userId", user_id.to_string()),
+            ("remarks", remarks_param(remarks)?),
+        ];
+        self.post(
+            super::endpoints::USER_REMARKS_SET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Delete the push remark name a user assigned to another user.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/user/delete-remark-for-push
+    pub async fn user_remarks_delete(
+        &self,
+        user_id: &str,
+        target_id: &str,
+    ) -> Result<RcResponse<()>, RongCloudError> {
+        let params = [("userId", user_id), ("targetId", target_id)];
+        self.post(
+            super::endpoints::USER_REMARKS_DEL,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
+
+    /// Query the push remark names of a user with pagination.
+    ///
+    /// See: https://doc.rongcloud.cn/imserver/server/v1/user/get-remark-for-push
+    pub async fn user_remarks_get(
+        &self,
+        user_id: &str,
+        page: Option<i32>,
+        size: Option<i32>,
+    ) -> Result<RcResponse<PushRemarkList>, RongCloudError> {
+        let mut params = vec![("userId", user_id.to_string())];
+        if let Some(page) = page {
+            params.push(("page", page.to_string()));
+        }
+        if let Some(size) = size {
+            params.push(("size", size.to_string()));
+        }
+        self.post(
+            super::endpoints::USER_REMARKS_GET,
+            &params,
+            "application/x-www-form-urlencoded",
+        )
+        .await
+    }
 }
 
 #[cfg(test)]
@@ -906,5 +1337,196 @@ mod tests {
 
         let _ = client.user_chat_ban_set(vec!["u1"], 1, "person").await;
         mock_ban.assert_async().await;
+
+        // Tags Get
+        let mock_tags = server
+            .mock("POST", "/user/tags/get.json")
+            .with_status(200)
+            .with_body(r#"{"code": 200, "result": {"u1": ["t1", "t2"]}}"#)
+            .create_async()
+            .await;
+
+        let tags = client
+            .user_tags_get(vec!["u1"])
+            .await
+            .unwrap()
+            .data
+            .unwrap();
+        mock_tags.assert_async().await;
+        assert_eq!(tags.result["u1"], vec!["t1", "t2"]);
+    }
+
+    #[tokio::test]
+    async fn test_user_tag_batch_set() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_first = server
+            .mock("POST", "/user/tag/batch/set.json")
+            .match_body(mockito::Matcher::Regex(
+                r#"^\{"userIds":\["u0","u1",.*"u999"\],"tags":\["vip"\]\}$"#.into(),
+            ))
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+        let mock_second = server
+            .mock("POST", "/user/tag/batch/set.json")
+            .match_body(r#"{"userIds":["u1000"],"tags":["vip"]}"#)
+            .with_status(200)
+            .with_body(r#"{"code": 1002, "msg": "invalid userId"}"#)
+            .create_async()
+            .await;
+
+        let ids: Vec<String> = (0..=USER_TAG_BATCH_MAX_USERS)
+            .map(|i| format!("u{i}"))
+            .collect();
+        let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+        let report = client.user_tag_batch_set(&ids, &["vip"]).await.unwrap();
+        mock_first.assert_async().await;
+        mock_second.assert_async().await;
+
+        assert_eq!(report.chunks.len(), 2);
+        assert!(!report.is_success());
+        assert_eq!(report.succeeded_user_count(), USER_TAG_BATCH_MAX_USERS);
+        let failed: Vec<_> = report.failed_chunks().collect();
+        assert_eq!(failed[0].index, 1);
+        assert!(matches!(
+            failed[0].outcome,
+            Err(RongCloudError::Api { code: 1002, .. })
+        ));
+
+        let result = client.user_tag_batch_set(&[], &["vip"]).await;
+        assert!(matches!(result, Err(RongCloudError::InvalidParameter(_))));
+    }
+
+    #[tokio::test]
+    async fn test_user_profile_batch_query() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock = server
+            .mock("POST", "/user/profile/batch/query.json")
+            .with_status(200)
+            .with_body(
+                r#"{"code": 200, "userList": [{"userId": "u1", "userProfile": "{\"name\":\"Tom\"}", "userExtProfile": {"ext_level": "3"}, "version": 2}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let result = client.user_profile_batch_query(vec!["u1"]).await;
+        mock.assert_async().await;
+        let users = result.unwrap().data.unwrap().users;
+        assert_eq!(users[0].profile.as_ref().unwrap()["name"], "Tom");
+        assert_eq!(users[0].ext_profile.as_ref().unwrap()["ext_level"], "3");
+        assert_eq!(users[0].version, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_user_block_push_period() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_set = server
+            .mock("POST", "/user/blockPushPeriod/set.json")
+            .match_body("userId=u1&startTime=22%3A00%3A00&period=480&level=1")
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+        let mock_get = server
+            .mock("POST", "/user/blockPushPeriod/get.json")
+            .match_body("userId=u1")
+            .with_status(200)
+            .with_body(
+                r#"{"code": 200, "data": {"startTime": "22:00:00", "period": "480", "unPushLevel": 1}}"#,
+            )
+            .create_async()
+            .await;
+
+        let quiet_period = QuietPeriod::new("22:00:00", 480)
+            .unwrap()
+            .level(NotificationLevel::Mention)
+            .unwrap();
+        let result = client.user_block_push_period_set("u1", &quiet_period).await;
+        mock_set.assert_async().await;
+        assert!(result.unwrap().is_success());
+
+        let result = client.user_block_push_period_get("u1").await;
+        mock_get.assert_async().await;
+        assert_eq!(result.unwrap().data.unwrap().data, Some(quiet_period));
+    }
+
+    #[test]
+    fn test_quiet_period_validate() {
+        assert!(QuietPeriod::new("23:59:59", QUIET_PERIOD_MAX_MINUTES).is_ok());
+        assert!(QuietPeriod::new("24:00:00", 60).is_err());
+        assert!(QuietPeriod::new("8:00:00", 60).is_err());
+        assert!(QuietPeriod::new("+1:00:00", 60).is_err());
+        assert!(QuietPeriod::new("08:+1:00", 60).is_err());
+        assert!(QuietPeriod::new("08:00:00", 0).is_err());
+        assert!(QuietPeriod::new("08:00:00", QUIET_PERIOD_MAX_MINUTES + 1).is_err());
+        let result = QuietPeriod::new("08:00:00", 60)
+            .unwrap()
+            .level(NotificationLevel::All);
+        assert!(matches!(result, Err(RongCloudError::InvalidParameter(_))));
+
+        let mut quiet_period = QuietPeriod::new("08:00:00", 60).unwrap();
+        quiet_period.level = Some(NotificationLevel::All);
+        assert!(matches!(
+            quiet_period.validate(),
+            Err(RongCloudError::InvalidParameter(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_user_remarks() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let config = RongCloudConfig::new("app_key", "app_secret").with_api_url(url);
+        let client = RongCloud::new(config);
+
+        let mock_set = server
+            .mock("POST", "/user/remarks/set.json")
+            .match_body(mockito::Matcher::UrlEncoded(
+                "remarks".into(),
+                r#"[{"id":"u2","remark":"Tom"},{"id":"u3","remark":"Amy"}]"#.into(),
+            ))
+            .with_status(200)
+            .with_body(r#"{"code": 200}"#)
+            .create_async()
+            .await;
+        let mock_get = server
+            .mock("POST", "/user/remarks/get.json")
+            .match_body("userId=u1&page=1&size=50")
+            .with_status(200)
+            .with_body(r#"{"code": 200, "total": 2, "users": [{"id": "u2", "remark": "Tom"}]}"#)
+            .create_async()
+            .await;
+
+        let result = client
+            .user_remarks_set("u1", &[("u2", "Tom"), ("u3", "Amy")])
+            .await;
+        mock_set.assert_async().await;
+        assert!(result.unwrap().is_success());
+
+        let list = client
+            .user_remarks_get("u1", Some(1), Some(50))
+            .await
+            .unwrap()
+            .data
+            .unwrap();
+        mock_get.assert_async().await;
+        assert_eq!(list.total, 2);
+        assert_eq!(list.remarks[0].remark, "Tom");
+
+        let result = client.user_remarks_set("u1", &[]).await;
+        assert!(matches!(result, Err(RongCloudError::InvalidParameter(_))));
     }
 }
@@ -4,10 +4,12 @@
 
 use super::config::RongCloudConfig;
 use super::error::RongCloudError;
+use super::quota::CustomPushQuota;
 use crate::util::{current_timestamp, generate_nonce, generate_signature};
 use reqwest::Client;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
+use std::sync::Mutex;
 
 /// SDK 版本
 pub const SDK_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -20,6 +22,8 @@ pub struct RongCloud {
     pub config: RongCloudConfig,
     /// HTTP 客户端
     http_client: Client,
+    /// 推送 Plus 本地调用配额
+    pub(crate) custom_push_quota: Mutex<CustomPushQuota>,
 }
 
 impl RongCloud {
@@ -37,6 +41,7 @@ impl RongCloud {
         Self {
             config,
             http_client,
+            custom_push_quota: Mutex::new(CustomPushQuota::default()),
         }
     }
 
@@ -11,6 +11,12 @@ pub enum RongCloudError {
     #[error("API error {code}: {msg}")]
     Api { code: i32, msg: String },
 
+    #[error("Invalid parameter: {0}")]
+    InvalidParameter(String),
+
+    #[error("Quota exceeded: {0}")]
+    QuotaExceeded(String),
+
     #[error("Unknown error")]
     Unknown,
 }
@@ -1,11 +1,13 @@
 //! 核心模块
 //!
-//! 包含客户端、配置、错误类型
+//! 包含客户端、配置、错误类型及本地调用配额
 
 mod client;
 mod config;
 mod error;
+mod quota;
 
 pub use client::{RongCloud, SDK_USER_AGENT, SDK_VERSION};
 pub use config::RongCloudConfig;
 pub use error::RongCloudError;
+pub use quota::{CUSTOM_PUSH_DAILY_LIMIT, CUSTOM_PUSH_HOURLY_LIMIT};
@@ -0,0 +1,129 @@
+//! 推送 Plus 本地调用配额
+//!
+//! 按滚动一小时窗口与北京时间自然日计数，达到上限后在本地直接拒绝
+
+use super::error::RongCloudError;
+use std::collections::VecDeque;
+
+/// 推送 Plus 每小时调用上限
+pub const CUSTOM_PUSH_HOURLY_LIMIT: u32 = 20;
+/// 推送 Plus 每个自然日调用上限
+pub const CUSTOM_PUSH_DAILY_LIMIT: u32 = 100;
+
+/// 小时配额的滚动窗口长度（秒）
+const HOUR_WINDOW_SECS: u64 = 3600;
+/// 自然日按北京时间（UTC+8）划分
+const BEIJING_OFFSET_SECS: u64 = 8 * 3600;
+
+/// 推送 Plus 本地配额计数
+#[derive(Debug, Default)]
+pub(crate) struct CustomPushQuota {
+    /// 最近一小时内已占用配额的调用时间（秒）
+    recent: VecDeque<u64>,
+    day: u64,
+    day_count: u32,
+}
+
+fn beijing_day(now: u64) -> u64 {
+    (now + BEIJING_OFFSET_SECS) / 86400
+}
+
+impl CustomPushQuota {
+    /// 占用一次调用配额，`now` 为 Unix 时间戳（秒）
+    pub(crate) fn try_acquire(&mut self, now: u64) -> Result<(), RongCloudError> {
+        while self
+            .recent
+            .front()
+            .is_some_and(|t| t + HOUR_WINDOW_SECS <= now)
+        {
+            self.recent.pop_front();
+        }
+        let day = beijing_day(now);
+        if day != self.day {
+            self.day = day;
+            self.day_count = 0;
+        }
+        if self.recent.len() >= CUSTOM_PUSH_HOURLY_LIMIT as usize {
+            return Err(RongCloudError::QuotaExceeded(format!(
+                "custom push is limited to {} calls per hour",
+                CUSTOM_PUSH_HOURLY_LIMIT
+            )));
+        }
+        if self.day_count >= CUSTOM_PUSH_DAILY_LIMIT {
+            return Err(RongCloudError::QuotaExceeded(format!(
+                "custom push is limited to {} calls per day",
+                CUSTOM_PUSH_DAILY_LIMIT
+            )));
+        }
+        self.recent.push_back(now);
+        self.day_count += 1;
+        Ok(())
+    }
+
+    /// 归还 `now` 时刻占用的配额，用于请求未成功发出的情况
+    pub(crate) fn release(&mut self, now: u64) {
+        if let Some(pos) = self.recent.iter().rposition(|&t| t == now) {
+            self.recent.remove(pos);
+        }
+        if beijing_day(now) == self.day {
+            self.day_count = self.day_count.saturating_sub(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_push_quota_rolling_hour() {
+        let mut quota = CustomPushQuota::default();
+        // 北京时间 2023-11-15 06:30:00，跨越整点不会重置小时配额
+        let start = 1_700_000_000 / 3600 * 3600 + 1800;
+        for _ in 0..CUSTOM_PUSH_HOURLY_LIMIT {
+            quota.try_acquire(start).unwrap();
+        }
+        assert!(matches!(
+            quota.try_acquire(start + 1800),
+            Err(RongCloudError::QuotaExceeded(_))
+        ));
+        assert!(matches!(
+            quota.try_acquire(start + HOUR_WINDOW_SECS - 1),
+            Err(RongCloudError::QuotaExceeded(_))
+        ));
+        quota.try_acquire(start + HOUR_WINDOW_SECS).unwrap();
+    }
+
+    #[test]
+    fn test_custom_push_quota_daily() {
+        let mut quota = CustomPushQuota::default();
+        // 北京时间 2023-11-15 06:00:00
+        let start = 1_700_000_000 / 3600 * 3600;
+        let full_hours = CUSTOM_PUSH_DAILY_LIMIT / CUSTOM_PUSH_HOURLY_LIMIT;
+        for hour in 0..full_hours as u64 {
+            for _ in 0..CUSTOM_PUSH_HOURLY_LIMIT {
+                quota.try_acquire(start + hour * HOUR_WINDOW_SECS).unwrap();
+            }
+        }
+        let next_hour = start + full_hours as u64 * HOUR_WINDOW_SECS;
+        assert!(matches!(
+            quota.try_acquire(next_hour),
+            Err(RongCloudError::QuotaExceeded(_))
+        ));
+        // 次日配额重置
+        quota.try_acquire(start + 24 * HOUR_WINDOW_SECS).unwrap();
+    }
+
+    #[test]
+    fn test_custom_push_quota_release() {
+        let mut quota = CustomPushQuota::default();
+        let now = 1_700_000_000;
+        for _ in 0..CUSTOM_PUSH_HOURLY_LIMIT {
+            quota.try_acquire(now).unwrap();
+        }
+        quota.release(now);
+        assert_eq!(quota.recent.len(), CUSTOM_PUSH_HOURLY_LIMIT as usize - 1);
+        assert_eq!(quota.day_count, CUSTOM_PUSH_HOURLY_LIMIT - 1);
+        quota.try_acquire(now + 1).unwrap();
+    }
+}
@@ -26,6 +26,8 @@ pub mod types;
 pub mod messages;
 // API 模块
 pub mod api;
+// 回调接收
+pub mod webhook;
 // 工具函数
 mod util;
 
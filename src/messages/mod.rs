@@ -79,6 +79,97 @@ pub trait Message: Serialize {
     }
 }
 
+// ============================================================================
+// 消息内容解析
+// ============================================================================
+
+/// 按消息类型解析后的消息内容
+///
+/// 用于解析融云回调及消息查询结果中的 `objectName` 与 `content`，未内置的消息类型保留原始 JSON
+#[derive(Debug, Clone)]
+pub enum MessageContent {
+    Txt(TxtMessage),
+    Img(ImgMessage),
+    Voice(VoiceMessage),
+    HQVoice(HQVoiceMessage),
+    ImgText(ImgTextMessage),
+    File(FileMessage),
+    LBS(LBSMessage),
+    Gif(GifMessage),
+    Sight(SightMessage),
+    Cmd(CmdMessage),
+    CmdNtf(CmdNtfMessage),
+    ContactNtf(ContactNtfMessage),
+    ProfileNtf(ProfileNtfMessage),
+    InfoNtf(InfoNtfMessage),
+    GroupNtf(GroupNtfMessage),
+    ReadReceipt(ReadReceiptMessage),
+    TypingStatus(TypingStatusMessage),
+    RecallCmd(RecallCmdMessage),
+    /// 自定义或未内置的消息类型
+    Custom {
+        /// 消息类型
+        object_name: String,
+        /// 消息内容 JSON
+        content: String,
+    },
+}
+
+impl MessageContent {
+    /// 根据消息类型解析消息内容 JSON
+    pub fn parse(object_name: &str, content: &str) -> Result<Self, serde_json::Error> {
+        Ok(match object_name {
+            message_type::TEXT => Self::Txt(serde_json::from_str(content)?),
+            message_type::IMAGE => Self::Img(serde_json::from_str(content)?),
+            message_type::VOICE => Self::Voice(serde_json::from_str(content)?),
+            message_type::HQ_VOICE => Self::HQVoice(serde_json::from_str(content)?),
+            message_type::IMAGE_TEXT => Self::ImgText(serde_json::from_str(content)?),
+            message_type::FILE => Self::File(serde_json::from_str(content)?),
+            message_type::LBS => Self::LBS(serde_json::from_str(content)?),
+            message_type::GIF => Self::Gif(serde_json::from_str(content)?),
+            message_type::SIGHT => Self::Sight(serde_json::from_str(content)?),
+            message_type::CMD => Self::Cmd(serde_json::from_str(content)?),
+            message_type::CMD_NTF => Self::CmdNtf(serde_json::from_str(content)?),
+            message_type::CONTACT_NTF => Self::ContactNtf(serde_json::from_str(content)?),
+            message_type::PROFILE_NTF => Self::ProfileNtf(serde_json::from_str(content)?),
+            message_type::INFO_NTF => Self::InfoNtf(serde_json::from_str(content)?),
+            message_type::GROUP_NTF => Self::GroupNtf(serde_json::from_str(content)?),
+            message_type::READ_RECEIPT => Self::ReadReceipt(serde_json::from_str(content)?),
+            message_type::TYPING_STATUS => Self::TypingStatus(serde_json::from_str(content)?),
+            message_type::RECALL_CMD => Self::RecallCmd(serde_json::from_str(content)?),
+            _ => Self::Custom {
+                object_name: object_name.to_string(),
+                content: content.to_string(),
+            },
+        })
+    }
+
+    /// 获取消息类型标识符
+    pub fn object_name(&self) -> &str {
+        match self {
+            Self::Txt(m) => m.message_type(),
+            Self::Img(m) => m.message_type(),
+            Self::Voice(m) => m.message_type(),
+            Self::HQVoice(m) => m.message_type(),
+            Self::ImgText(m) => m.message_type(),
+            Self::File(m) => m.message_type(),
+            Self::LBS(m) => m.message_type(),
+            Self::Gif(m) => m.message_type(),
+            Self::Sight(m) => m.message_type(),
+            Self::Cmd(m) => m.message_type(),
+            Self::CmdNtf(m) => m.message_type(),
+            Self::ContactNtf(m) => m.message_type(),
+            Self::ProfileNtf(m) => m.message_type(),
+            Self::InfoNtf(m) => m.message_type(),
+            Self::GroupNtf(m) => m.message_type(),
+            Self::ReadReceipt(m) => m.message_type(),
+            Self::TypingStatus(m) => m.message_type(),
+            Self::RecallCmd(m) => m.message_type(),
+            Self::Custom { object_name, .. } => object_name,
+        }
+    }
+}
+
 // ============================================================================
 // 用户信息（可嵌入消息）
 // ============================================================================
@@ -177,6 +268,17 @@ mod tests {
         assert_eq!(message_type::VOICE, "RC:VcMsg");
     }
 
+    #[test]
+    fn test_message_content_parse() {
+        let content = MessageContent::parse(message_type::TEXT, r#"{"content":"hi"}"#).unwrap();
+        assert!(matches!(&content, MessageContent::Txt(m) if m.content == "hi"));
+        assert_eq!(content.object_name(), message_type::TEXT);
+
+        let custom = MessageContent::parse("App:Order", r#"{"orderId":"A1"}"#).unwrap();
+        assert_eq!(custom.object_name(), "App:Order");
+        assert!(MessageContent::parse(message_type::TEXT, "not json").is_err());
+    }
+
     #[test]
     fn test_user_info_builder() {
         let user = UserInfo::new("user1", "张三").with_portrait("http://example.com/avatar.png");
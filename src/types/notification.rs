@@ -0,0 +1,55 @@
+//! 免打扰级别定义
+//!
+//! 会话免打扰与超级群默认免打扰共用同一套级别取值
+
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// 免打扰级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize_repr, Deserialize_repr)]
+#[repr(i8)]
+pub enum NotificationLevel {
+    /// 全部消息通知
+    All = -1,
+    /// 未设置（按上一级配置处理）
+    #[default]
+    NotSet = 0,
+    /// 仅 @ 消息通知
+    Mention = 1,
+    /// 仅 @ 指定用户通知
+    MentionUsers = 2,
+    /// 仅 @ 群全员通知
+    MentionAll = 4,
+    /// 不接收通知
+    Blocked = 5,
+}
+
+impl NotificationLevel {
+    /// 获取免打扰级别的中文描述
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::All => "全部消息通知",
+            Self::NotSet => "未设置",
+            Self::Mention => "仅 @ 消息通知",
+            Self::MentionUsers => "仅 @ 指定用户通知",
+            Self::MentionAll => "仅 @ 群全员通知",
+            Self::Blocked => "不接收通知",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notification_level_serde() {
+        assert_eq!(
+            serde_json::to_string(&NotificationLevel::All).unwrap(),
+            "-1"
+        );
+        assert_eq!(
+            serde_json::from_str::<NotificationLevel>("5").unwrap(),
+            NotificationLevel::Blocked
+        );
+    }
+}
@@ -1,4 +1,6 @@
 use rand::Rng;
+use serde::de::{DeserializeOwned, Error as _};
+use serde::{Deserialize, Deserializer};
 use sha1::{Digest, Sha1};
 
 pub fn generate_signature(app_secret: &str, nonce: &str, timestamp: &str) -> String {
@@ -23,6 +25,26 @@ pub fn current_timestamp() -> String {
     since_the_epoch.as_secs().to_string()
 }
 
+/// 反序列化以 JSON 字符串形式内嵌在响应中的字段
+///
+/// 同时兼容直接返回 JSON 对象的情况，空字符串与 null 视为 `None`
+pub(crate) fn deserialize_embedded_json<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::String(s)) if s.is_empty() => Ok(None),
+        Some(serde_json::Value::String(s)) => {
+            serde_json::from_str(&s).map(Some).map_err(D::Error::custom)
+        }
+        Some(value) => serde_json::from_value(value)
+            .map(Some)
+            .map_err(D::Error::custom),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,284 @@
+//! 消息回调服务
+//!
+//! 消息下发前融云将其 POST 到业务服务器，业务服务器返回放行、拦截或替换消息内容
+
+use super::routing::channel_conversation_type;
+use super::{WebhookError, WebhookVerifier};
+use crate::messages::{Message, MessageContent};
+use crate::types::ConversationType;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+
+#[derive(Deserialize)]
+struct CallbackRequest {
+    #[serde(rename = "fromUserId")]
+    from_user_id: String,
+    #[serde(rename = "targetId")]
+    target_id: String,
+    #[serde(rename = "toUserIds", default)]
+    to_user_ids: Vec<String>,
+    #[serde(rename = "msgType")]
+    object_name: String,
+    content: String,
+    #[serde(rename = "channelType")]
+    channel_type: String,
+    #[serde(rename = "msgTimeStamp", default)]
+    msg_timestamp: i64,
+    #[serde(rename = "messageId", default)]
+    message_id: String,
+    #[serde(default)]
+    os: Option<String>,
+    #[serde(rename = "busChannel", default)]
+    bus_channel: Option<String>,
+}
+
+/// 待审核的消息
+#[derive(Debug, Clone)]
+pub struct CallbackMessage {
+    /// 发送人 ID
+    pub from_user_id: String,
+    /// 目标 ID，单聊为用户 ID，群聊为群组 ID
+    pub target_id: String,
+    /// 定向接收人 ID 列表
+    pub to_user_ids: Vec<String>,
+    /// 按消息类型解析后的消息内容
+    pub content: MessageContent,
+    /// 会话类型，如 `PERSON`、`GROUP`、`TEMPGROUP`、`ULTRAGROUP`
+    pub channel_type: String,
+    /// 消息发送时间（毫秒）
+    pub msg_timestamp: i64,
+    /// 消息 ID
+    pub message_id: String,
+    /// 发送端平台
+    pub os: Option<String>,
+    /// 超级群频道 ID
+    pub bus_channel: Option<String>,
+}
+
+impl CallbackMessage {
+    /// 解析 JSON 请求体（不校验签名）
+    pub fn from_json(body: &str) -> Result<Self, WebhookError> {
+        let request: CallbackRequest = serde_json::from_str(body)?;
+        Ok(Self {
+            content: MessageContent::parse(&request.object_name, &request.content)?,
+            from_user_id: request.from_user_id,
+            target_id: request.target_id,
+            to_user_ids: request.to_user_ids,
+            channel_type: request.channel_type,
+            msg_timestamp: request.msg_timestamp,
+            message_id: request.message_id,
+            os: request.os,
+            bus_channel: request.bus_channel,
+        })
+    }
+
+    /// 消息类型标识符
+    pub fn object_name(&self) -> &str {
+        self.content.object_name()
+    }
+
+    /// 将 `channel_type` 转换为会话类型，未知类型返回 `None`
+    pub fn conversation_type(&self) -> Option<ConversationType> {
+        channel_conversation_type(&self.channel_type)
+    }
+}
+
+/// 替换后的消息内容
+#[derive(Debug, Clone, PartialEq)]
+pub struct Replacement {
+    /// 消息类型
+    pub object_name: String,
+    /// 消息内容 JSON
+    pub content: String,
+}
+
+/// 消息审核结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum Decision {
+    /// 放行原消息
+    Pass,
+    /// 拦截消息，不下发
+    Block,
+    /// 替换消息内容后下发
+    Replace(Replacement),
+}
+
+impl Decision {
+    /// 使用内置或自定义消息类型替换原消息
+    pub fn replace<M: Message>(message: &M) -> Result<Self, serde_json::Error> {
+        Ok(Self::Replace(Replacement {
+            object_name: message.message_type().to_string(),
+            content: message.to_json()?,
+        }))
+    }
+
+    /// 序列化为回调响应 JSON
+    pub fn to_response_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&CallbackResponse::from(self))
+    }
+}
+
+/// 回调响应体
+#[derive(Debug, Serialize)]
+pub struct CallbackResponse<'a> {
+    /// 是否下发消息。0: 拦截, 1: 下发
+    pub pass: i32,
+    /// 替换后的消息内容 JSON
+    #[serde(rename = "replaceContent", skip_serializing_if = "Option::is_none")]
+    pub replace_content: Option<&'a str>,
+    /// 替换后的消息类型
+    #[serde(rename = "replaceMsgType", skip_serializing_if = "Option::is_none")]
+    pub replace_msg_type: Option<&'a str>,
+}
+
+impl<'a> From<&'a Decision> for CallbackResponse<'a> {
+    fn from(decision: &'a Decision) -> Self {
+        match decision {
+            Decision::Pass => Self {
+                pass: 1,
+                replace_content: None,
+                replace_msg_type: None,
+            },
+            Decision::Block => Self {
+                pass: 0,
+                replace_content: None,
+                replace_msg_type: None,
+            },
+            Decision::Replace(replacement) => Self {
+                pass: 1,
+                replace_content: Some(&replacement.content),
+                replace_msg_type: Some(&replacement.object_name),
+            },
+        }
+    }
+}
+
+/// 消息审核处理器返回的错误，由 `MessageCallback::process` 包装为 `WebhookError::Handler`
+pub type HandlerError = Box<dyn std::error::Error + Send + Sync>;
+
+/// 消息审核处理器
+///
+/// 同步闭包 `Fn(&CallbackMessage) -> Result<Decision, HandlerError>` 已实现此 trait，
+/// 处理器可直接用 `?` 传播 `Decision::replace` 或业务代码的错误
+pub trait MessageCallbackHandler {
+    fn handle(
+        &self,
+        message: &CallbackMessage,
+    ) -> impl Future<Output = Result<Decision, HandlerError>> + Send;
+}
+
+impl<F> MessageCallbackHandler for F
+where
+    F: Fn(&CallbackMessage) -> Result<Decision, HandlerError>,
+{
+    fn handle(
+        &self,
+        message: &CallbackMessage,
+    ) -> impl Future<Output = Result<Decision, HandlerError>> + Send {
+        std::future::ready(self(message))
+    }
+}
+
+/// 消息回调服务
+///
+/// 校验签名、解析消息、调用处理器并生成响应 JSON
+pub struct MessageCallback<H> {
+    verifier: WebhookVerifier,
+    handler: H,
+}
+
+impl<H: MessageCallbackHandler> MessageCallback<H> {
+    pub fn new(verifier: WebhookVerifier, handler: H) -> Self {
+        Self { verifier, handler }
+    }
+
+    /// 处理一次回调，返回应答给融云的 JSON
+    ///
+    /// `query` 为回调 URL 的查询字符串，`body` 为 JSON 请求体
+    pub async fn process(&self, query: &str, body: &str) -> Result<String, WebhookError> {
+        self.verifier.verify_query(query)?;
+        let message = CallbackMessage::from_json(body)?;
+        let decision = self
+            .handler
+            .handle(&message)
+            .await
+            .map_err(WebhookError::Handler)?;
+        Ok(decision.to_response_json()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::TxtMessage;
+    use crate::webhook::signed_query;
+
+    const BODY: &str = r#"{
+        "fromUserId": "u1",
+        "targetId": "g1",
+        "toUserIds": [],
+        "msgType": "RC:TxtMsg",
+        "content": "{\"content\":\"buy cheap watches\"}",
+        "channelType": "GROUP",
+        "msgTimeStamp": 1700000000000,
+        "messageId": "M1",
+        "os": "iOS"
+    }"#;
+
+    fn moderate(message: &CallbackMessage) -> Result<Decision, HandlerError> {
+        Ok(match &message.content {
+            MessageContent::Txt(text) if text.content.contains("cheap") => {
+                Decision::replace(&TxtMessage::new("***"))?
+            }
+            MessageContent::Txt(_) => Decision::Pass,
+            _ => Decision::Block,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_process_callback() {
+        let callback = MessageCallback::new(WebhookVerifier::new("secret"), moderate);
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as i64;
+
+        let response = callback
+            .process(&signed_query("secret", "n1", now), BODY)
+            .await
+            .unwrap();
+        assert_eq!(
+            response,
+            r#"{"pass":1,"replaceContent":"{\"content\":\"***\"}","replaceMsgType":"RC:TxtMsg"}"#
+        );
+
+        let result = callback
+            .process(&signed_query("other", "n2", now), BODY)
+            .await;
+        assert!(matches!(result, Err(WebhookError::InvalidSignature)));
+
+        let failing = MessageCallback::new(
+            WebhookVerifier::new("secret"),
+            |_: &CallbackMessage| -> Result<Decision, HandlerError> {
+                Err("moderation service unavailable".into())
+            },
+        );
+        let result = failing
+            .process(&signed_query("secret", "n3", now), BODY)
+            .await;
+        assert!(
+            matches!(result, Err(WebhookError::Handler(e)) if e.to_string() == "moderation service unavailable")
+        );
+    }
+
+    #[test]
+    fn test_parse_and_decisions() {
+        let message = CallbackMessage::from_json(BODY).unwrap();
+        assert_eq!(message.from_user_id, "u1");
+        assert_eq!(message.object_name(), "RC:TxtMsg");
+        assert_eq!(message.conversation_type(), Some(ConversationType::Group));
+
+        assert_eq!(Decision::Pass.to_response_json().unwrap(), r#"{"pass":1}"#);
+        assert_eq!(Decision::Block.to_response_json().unwrap(), r#"{"pass":0}"#);
+    }
+}
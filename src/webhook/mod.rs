@@ -0,0 +1,215 @@
+//! 融云回调接收
+//!
+//! 校验融云向业务服务器发起的回调请求，并解析为强类型结构。
+//! 回调 URL 上携带 `nonce`、`signTimestamp`、`signature` 三个参数，
+//! 签名算法与 API 请求签名相同：`sha1(App Secret + nonce + signTimestamp)`。
+
+mod callback;
+mod presence;
+mod routing;
+
+pub use callback::{
+    CallbackMessage, CallbackResponse, Decision, HandlerError, MessageCallback,
+    MessageCallbackHandler, Replacement,
+};
+pub use presence::{DevicePresence, PresenceEvent, PresenceInfo, PresenceTable};
+pub use routing::RoutedMessage;
+
+use crate::util::generate_signature;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use thiserror::Error;
+
+/// 默认允许的回调时间偏差
+pub const DEFAULT_TIMESTAMP_TOLERANCE: Duration = Duration::from_secs(300);
+
+/// 回调处理错误
+#[derive(Error, Debug)]
+pub enum WebhookError {
+    #[error("Invalid signature")]
+    InvalidSignature,
+
+    #[error("Timestamp outside the allowed window")]
+    TimestampExpired,
+
+    #[error("Nonce has already been used")]
+    ReplayedNonce,
+
+    #[error("Malformed request: {0}")]
+    MalformedRequest(String),
+
+    #[error("Invalid message content: {0}")]
+    InvalidContent(#[from] serde_json::Error),
+
+    #[error("Callback handler failed: {0}")]
+    Handler(Box<dyn std::error::Error + Send + Sync>),
+}
+
+/// 回调签名参数，取自回调 URL 的查询字符串
+#[derive(Debug, Clone, Deserialize)]
+pub struct SignatureParams {
+    /// 随机数
+    pub nonce: String,
+    /// 时间戳（毫秒）
+    #[serde(rename = "signTimestamp")]
+    pub sign_timestamp: i64,
+    /// 签名
+    pub signature: String,
+}
+
+impl SignatureParams {
+    /// 从查询字符串（不含 `?`）解析签名参数
+    pub fn from_query(query: &str) -> Result<Self, WebhookError> {
+        serde_urlencoded::from_str(query).map_err(|e| WebhookError::MalformedRequest(e.to_string()))
+    }
+}
+
+/// 回调签名校验器
+///
+/// 校验签名与时间窗口，并在窗口期内记录已使用的 nonce 以拒绝重放请求。
+/// 同一应用的各类回调可共用一个校验器。
+pub struct WebhookVerifier {
+    app_secret: String,
+    tolerance: Duration,
+    /// nonce -> 过期时间（毫秒）
+    seen_nonces: Mutex<HashMap<String, i64>>,
+}
+
+impl WebhookVerifier {
+    pub fn new(app_secret: impl Into<String>) -> Self {
+        Self {
+            app_secret: app_secret.into(),
+            tolerance: DEFAULT_TIMESTAMP_TOLERANCE,
+            seen_nonces: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 设置允许的时间偏差
+    pub fn with_tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// 校验回调 URL 的查询字符串
+    pub fn verify_query(&self, query: &str) -> Result<(), WebhookError> {
+        self.verify(&SignatureParams::from_query(query)?)
+    }
+
+    /// 校验签名参数
+    pub fn verify(&self, params: &SignatureParams) -> Result<(), WebhookError> {
+        self.verify_at(params, current_millis())
+    }
+
+    pub(crate) fn verify_at(
+        &self,
+        params: &SignatureParams,
+        now_ms: i64,
+    ) -> Result<(), WebhookError> {
+        let expected = generate_signature(
+            &self.app_secret,
+            &params.nonce,
+            &params.sign_timestamp.to_string(),
+        );
+        if !constant_time_eq(
+            expected.as_bytes(),
+            params.signature.to_ascii_lowercase().as_bytes(),
+        ) {
+            return Err(WebhookError::InvalidSignature);
+        }
+
+        let tolerance_ms = self.tolerance.as_millis() as i64;
+        if (now_ms - params.sign_timestamp).abs() > tolerance_ms {
+            return Err(WebhookError::TimestampExpired);
+        }
+
+        let mut seen = self.seen_nonces.lock().unwrap_or_else(|e| e.into_inner());
+        // 与窗口判断保持一致：恰好位于窗口边界的请求仍然有效，nonce 也需保留
+        seen.retain(|_, expires_at| *expires_at >= now_ms);
+        if seen.contains_key(&params.nonce) {
+            return Err(WebhookError::ReplayedNonce);
+        }
+        // 时间戳超出窗口后的请求会被拒绝，nonce 只需保留到那时
+        seen.insert(params.nonce.clone(), params.sign_timestamp + tolerance_ms);
+        Ok(())
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn current_millis() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+/// 解析表单编码的回调请求体
+pub(crate) fn parse_form<T: serde::de::DeserializeOwned>(body: &str) -> Result<T, WebhookError> {
+    serde_urlencoded::from_str(body).map_err(|e| WebhookError::MalformedRequest(e.to_string()))
+}
+
+#[cfg(test)]
+pub(crate) fn signed_query(app_secret: &str, nonce: &str, sign_timestamp: i64) -> String {
+    let signature = generate_signature(app_secret, nonce, &sign_timestamp.to_string());
+    format!("nonce={nonce}&signTimestamp={sign_timestamp}&signature={signature}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000_000;
+
+    #[test]
+    fn test_verify_signature_and_window() {
+        let verifier = WebhookVerifier::new("secret");
+        let params = SignatureParams::from_query(&signed_query("secret", "n1", NOW)).unwrap();
+        assert!(verifier.verify_at(&params, NOW + 1000).is_ok());
+
+        let forged = SignatureParams::from_query(&signed_query("other", "n2", NOW)).unwrap();
+        assert!(matches!(
+            verifier.verify_at(&forged, NOW),
+            Err(WebhookError::InvalidSignature)
+        ));
+
+        let stale = SignatureParams::from_query(&signed_query("secret", "n3", NOW)).unwrap();
+        let late = NOW + DEFAULT_TIMESTAMP_TOLERANCE.as_millis() as i64 + 1;
+        assert!(matches!(
+            verifier.verify_at(&stale, late),
+            Err(WebhookError::TimestampExpired)
+        ));
+    }
+
+    #[test]
+    fn test_reject_replayed_nonce() {
+        let verifier = WebhookVerifier::new("secret");
+        let params = SignatureParams::from_query(&signed_query("secret", "n1", NOW)).unwrap();
+        assert!(verifier.verify_at(&params, NOW).is_ok());
+        assert!(matches!(
+            verifier.verify_at(&params, NOW + 10),
+            Err(WebhookError::ReplayedNonce)
+        ));
+
+        // 窗口边界的最后一毫秒仍须识别为重放
+        let tolerance_ms = DEFAULT_TIMESTAMP_TOLERANCE.as_millis() as i64;
+        let params = SignatureParams::from_query(&signed_query("secret", "n2", NOW)).unwrap();
+        assert!(verifier.verify_at(&params, NOW).is_ok());
+        assert!(matches!(
+            verifier.verify_at(&params, NOW + tolerance_ms),
+            Err(WebhookError::ReplayedNonce)
+        ));
+        assert!(matches!(
+            verifier.verify_at(&params, NOW + tolerance_ms + 1),
+            Err(WebhookError::TimestampExpired)
+        ));
+
+        assert!(matches!(
+            verifier.verify_query("nonce=n1"),
+            Err(WebhookError::MalformedRequest(_))
+        ));
+    }
+}
@@ -0,0 +1,276 @@
+//! 用户在线状态订阅
+//!
+//! 融云以 JSON 数组形式批量推送用户上线、离线、登出事件
+
+use super::{WebhookError, WebhookVerifier};
+use crate::util::deserialize_embedded_json;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Deserialize)]
+struct PresenceRecord {
+    #[serde(rename = "userid")]
+    user_id: String,
+    #[serde(default, deserialize_with = "deserialize_embedded_json")]
+    status: Option<u8>,
+    #[serde(default)]
+    os: String,
+    time: i64,
+    #[serde(rename = "clientIp", default)]
+    client_ip: Option<String>,
+}
+
+/// 在线状态事件详情
+#[derive(Debug, Clone, PartialEq)]
+pub struct PresenceInfo {
+    /// 用户 ID
+    pub user_id: String,
+    /// 客户端平台，如 `iOS`、`Android`、`Websocket`、`PC`
+    pub os: String,
+    /// 状态变化时间（毫秒）
+    pub time: i64,
+    /// 客户端 IP（可选）
+    pub client_ip: Option<String>,
+}
+
+/// 用户在线状态事件
+#[derive(Debug, Clone, PartialEq)]
+pub enum PresenceEvent {
+    /// 上线
+    Online(PresenceInfo),
+    /// 离线（断开连接，仍可收推送）
+    Offline(PresenceInfo),
+    /// 登出（主动退出登录）
+    Logout(PresenceInfo),
+}
+
+impl PresenceEvent {
+    /// 校验签名并解析状态事件
+    ///
+    /// `query` 为回调 URL 的查询字符串，`body` 为 JSON 数组请求体
+    pub fn receive(
+        verifier: &WebhookVerifier,
+        query: &str,
+        body: &str,
+    ) -> Result<Vec<Self>, WebhookError> {
+        verifier.verify_query(query)?;
+        Self::parse_batch(body)
+    }
+
+    /// 解析 JSON 数组请求体（不校验签名）
+    pub fn parse_batch(body: &str) -> Result<Vec<Self>, WebhookError> {
+        let records: Vec<PresenceRecord> = serde_json::from_str(body)?;
+        records
+            .into_iter()
+            .map(|record| {
+                let status = record.status;
+                let info = PresenceInfo {
+                    user_id: record.user_id,
+                    os: record.os,
+                    time: record.time,
+                    client_ip: record.client_ip,
+                };
+                match status {
+                    Some(0) => Ok(Self::Online(info)),
+                    Some(1) => Ok(Self::Offline(info)),
+                    Some(2) => Ok(Self::Logout(info)),
+                    other => Err(WebhookError::MalformedRequest(format!(
+                        "unknown presence status {:?} for user {}",
+                        other, info.user_id
+                    ))),
+                }
+            })
+            .collect()
+    }
+
+    pub fn info(&self) -> &PresenceInfo {
+        match self {
+            Self::Online(info) | Self::Offline(info) | Self::Logout(info) => info,
+        }
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.info().user_id
+    }
+
+    pub fn time(&self) -> i64 {
+        self.info().time
+    }
+
+    pub fn is_online(&self) -> bool {
+        matches!(self, Self::Online(_))
+    }
+}
+
+/// 单个设备（客户端平台）的当前在线状态
+#[derive(Debug, Clone, PartialEq)]
+pub struct DevicePresence {
+    /// 客户端平台
+    pub os: String,
+    /// 是否在线
+    pub online: bool,
+    /// 最近一次状态变化时间（毫秒）
+    pub updated_at: i64,
+}
+
+/// 内存在线状态表
+///
+/// 按用户与客户端平台分别记录最近一次状态，任一设备在线即视为用户在线。
+/// 时间早于当前记录的迟到事件会被忽略；同一毫秒内的上线与离线事件以离线为准，
+/// 结果与投递顺序无关
+#[derive(Debug, Default)]
+pub struct PresenceTable {
+    users: HashMap<String, HashMap<String, DevicePresence>>,
+}
+
+impl PresenceTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 应用单个事件，事件未改变已记录状态时返回 `false`
+    pub fn apply(&mut self, event: &PresenceEvent) -> bool {
+        let info = event.info();
+        let online = event.is_online();
+        let devices = self.users.entry(info.user_id.clone()).or_default();
+        if let Some(current) = devices.get(&info.os) {
+            let newer = info.time > current.updated_at;
+            let tie_goes_offline = info.time == current.updated_at && current.online && !online;
+            if !newer && !tie_goes_offline {
+                return false;
+            }
+        }
+        devices.insert(
+            info.os.clone(),
+            DevicePresence {
+                os: info.os.clone(),
+                online,
+                updated_at: info.time,
+            },
+        );
+        true
+    }
+
+    /// 按时间顺序应用一批事件，返回实际生效的事件数
+    pub fn apply_all(&mut self, events: &[PresenceEvent]) -> usize {
+        let mut ordered: Vec<&PresenceEvent> = events.iter().collect();
+        ordered.sort_by_key(|e| e.time());
+        ordered.into_iter().filter(|e| self.apply(e)).count()
+    }
+
+    /// 用户各设备的状态
+    pub fn devices(&self, user_id: &str) -> impl Iterator<Item = &DevicePresence> {
+        self.users.get(user_id).into_iter().flat_map(|d| d.values())
+    }
+
+    /// 用户在指定客户端平台上的状态
+    pub fn device(&self, user_id: &str, os: &str) -> Option<&DevicePresence> {
+        self.users.get(user_id).and_then(|d| d.get(os))
+    }
+
+    /// 用户是否有设备在线，未收到过事件的用户视为离线
+    pub fn is_online(&self, user_id: &str) -> bool {
+        self.devices(user_id).any(|d| d.online)
+    }
+
+    /// 当前在线的用户 ID
+    pub fn online_users(&self) -> impl Iterator<Item = &str> {
+        self.users
+            .iter()
+            .filter(|(_, devices)| devices.values().any(|d| d.online))
+            .map(|(id, _)| id.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::webhook::signed_query;
+
+    #[test]
+    fn test_receive_presence_events() {
+        let verifier = WebhookVerifier::new("secret");
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as i64;
+        let query = signed_query("secret", "n1", now);
+        let body = r#"[
+            {"userid": "u1", "status": "0", "os": "iOS", "time": 1000, "clientIp": "10.0.0.1"},
+            {"userid": "u2", "status": 2, "os": "Android", "time": 1001}
+        ]"#;
+
+        let events = PresenceEvent::receive(&verifier, &query, body).unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(events[0].is_online());
+        assert_eq!(events[0].info().client_ip.as_deref(), Some("10.0.0.1"));
+        assert!(matches!(&events[1], PresenceEvent::Logout(info) if info.os == "Android"));
+
+        let bad = r#"[{"userid": "u1", "status": "9", "os": "iOS", "time": 1}]"#;
+        assert!(matches!(
+            PresenceEvent::parse_batch(bad),
+            Err(WebhookError::MalformedRequest(_))
+        ));
+    }
+
+    #[test]
+    fn test_presence_table_ignores_late_events() {
+        let body = r#"[
+            {"userid": "u1", "status": "1", "os": "iOS", "time": 3000},
+            {"userid": "u1", "status": "0", "os": "iOS", "time": 1000},
+            {"userid": "u2", "status": "0", "os": "PC", "time": 2000}
+        ]"#;
+        let events = PresenceEvent::parse_batch(body).unwrap();
+
+        let mut table = PresenceTable::new();
+        assert_eq!(table.apply_all(&events), 3);
+        assert!(!table.is_online("u1"));
+        assert!(table.is_online("u2"));
+
+        // 迟到的上线事件早于已记录的离线事件
+        assert!(!table.apply(&events[1]));
+        assert!(!table.is_online("u1"));
+        assert_eq!(table.online_users().collect::<Vec<_>>(), vec!["u2"]);
+    }
+
+    #[test]
+    fn test_presence_table_multiple_devices() {
+        let body = r#"[
+            {"userid": "u1", "status": "0", "os": "iOS", "time": 1000},
+            {"userid": "u1", "status": "0", "os": "PC", "time": 1500},
+            {"userid": "u1", "status": "1", "os": "PC", "time": 2000}
+        ]"#;
+        let events = PresenceEvent::parse_batch(body).unwrap();
+
+        let mut table = PresenceTable::new();
+        assert_eq!(table.apply_all(&events[..2]), 2);
+        assert_eq!(table.devices("u1").count(), 2);
+
+        // PC 离线不影响 iOS 在线
+        assert!(table.apply(&events[2]));
+        assert!(table.is_online("u1"));
+        assert!(!table.device("u1", "PC").unwrap().online);
+        assert!(table.device("u1", "iOS").unwrap().online);
+        assert_eq!(table.online_users().collect::<Vec<_>>(), vec!["u1"]);
+    }
+
+    #[test]
+    fn test_presence_table_same_millisecond() {
+        let body = r#"[
+            {"userid": "u1", "status": "1", "os": "iOS", "time": 1000},
+            {"userid": "u1", "status": "0", "os": "iOS", "time": 1000}
+        ]"#;
+        let events = PresenceEvent::parse_batch(body).unwrap();
+
+        // 同一毫秒内离线优先，与投递顺序无关
+        let mut table = PresenceTable::new();
+        assert_eq!(table.apply_all(&events), 1);
+        assert!(!table.is_online("u1"));
+
+        let mut table = PresenceTable::new();
+        assert!(table.apply(&events[1]));
+        assert!(table.apply(&events[0]));
+        assert!(!table.apply(&events[1]));
+        assert!(!table.is_online("u1"));
+    }
+}
@@ -0,0 +1,148 @@
+//! 全量消息路由
+//!
+//! 融云将应用内的消息以表单形式 POST 到业务服务器
+
+use super::{WebhookError, WebhookVerifier, parse_form};
+use crate::messages::MessageContent;
+use crate::types::ConversationType;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct RoutedMessageForm {
+    #[serde(rename = "fromUserId")]
+    from_user_id: String,
+    #[serde(rename = "toUserId")]
+    to_user_id: String,
+    #[serde(rename = "objectName")]
+    object_name: String,
+    content: String,
+    #[serde(rename = "channelType")]
+    channel_type: String,
+    #[serde(rename = "msgTimestamp")]
+    msg_timestamp: i64,
+    #[serde(rename = "msgUID")]
+    msg_uid: String,
+    #[serde(rename = "sensitiveType", default)]
+    sensitive_type: Option<i32>,
+    #[serde(default)]
+    source: Option<String>,
+}
+
+/// 路由到业务服务器的消息
+#[derive(Debug, Clone)]
+pub struct RoutedMessage {
+    /// 发送人 ID
+    pub from_user_id: String,
+    /// 目标 ID，单聊为用户 ID，群聊为群组 ID
+    pub to_user_id: String,
+    /// 按消息类型解析后的消息内容
+    pub content: MessageContent,
+    /// 会话类型，如 `PERSON`、`GROUP`、`TEMPGROUP`、`ULTRAGROUP`
+    pub channel_type: String,
+    /// 消息发送时间（毫秒）
+    pub msg_timestamp: i64,
+    /// 消息唯一标识
+    pub msg_uid: String,
+    /// 敏感词类型。0: 不含敏感词, 1: 含屏蔽敏感词, 2: 含替换敏感词
+    pub sensitive_type: Option<i32>,
+    /// 消息来源，如 `iOS`、`Android`、`Websocket`、`Server`
+    pub source: Option<String>,
+}
+
+impl RoutedMessage {
+    /// 校验签名并解析路由请求
+    ///
+    /// `query` 为回调 URL 的查询字符串，`body` 为表单编码的请求体
+    pub fn receive(
+        verifier: &WebhookVerifier,
+        query: &str,
+        body: &str,
+    ) -> Result<Self, WebhookError> {
+        verifier.verify_query(query)?;
+        Self::from_form(body)
+    }
+
+    /// 解析表单编码的请求体（不校验签名）
+    pub fn from_form(body: &str) -> Result<Self, WebhookError> {
+        let form: RoutedMessageForm = parse_form(body)?;
+        Ok(Self {
+            content: MessageContent::parse(&form.object_name, &form.content)?,
+            from_user_id: form.from_user_id,
+            to_user_id: form.to_user_id,
+            channel_type: form.channel_type,
+            msg_timestamp: form.msg_timestamp,
+            msg_uid: form.msg_uid,
+            sensitive_type: form.sensitive_type,
+            source: form.source,
+        })
+    }
+
+    /// 消息类型标识符
+    pub fn object_name(&self) -> &str {
+        self.content.object_name()
+    }
+
+    /// 将 `channel_type` 转换为会话类型，未知类型返回 `None`
+    pub fn conversation_type(&self) -> Option<ConversationType> {
+        channel_conversation_type(&self.channel_type)
+    }
+}
+
+pub(crate) fn channel_conversation_type(channel_type: &str) -> Option<ConversationType> {
+    match channel_type {
+        "PERSON" | "PERSONS" => Some(ConversationType::Private),
+        "GROUP" => Some(ConversationType::Group),
+        "TEMPGROUP" => Some(ConversationType::ChatRoom),
+        "CUSTOMERSERVICE" => Some(ConversationType::CustomerService),
+        "NOTIFY" => Some(ConversationType::System),
+        "MC" => Some(ConversationType::AppPublicService),
+        "MP" => Some(ConversationType::PublicService),
+        "ULTRAGROUP" => Some(ConversationType::UltraGroup),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::webhook::signed_query;
+
+    const BODY: &str = "fromUserId=u1&toUserId=g1&objectName=RC%3ATxtMsg\
+        &content=%7B%22content%22%3A%22hello%22%7D&channelType=GROUP\
+        &msgTimestamp=1700000000000&msgUID=5FSC-ABCD-1234&sensitiveType=0&source=iOS";
+
+    #[test]
+    fn test_receive_routed_message() {
+        let verifier = WebhookVerifier::new("secret");
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as i64;
+        let query = signed_query("secret", "n1", now);
+
+        let message = RoutedMessage::receive(&verifier, &query, BODY).unwrap();
+        assert_eq!(message.from_user_id, "u1");
+        assert_eq!(message.conversation_type(), Some(ConversationType::Group));
+        assert_eq!(message.msg_uid, "5FSC-ABCD-1234");
+        assert!(matches!(&message.content, MessageContent::Txt(m) if m.content == "hello"));
+
+        assert!(matches!(
+            RoutedMessage::receive(&verifier, &query, BODY),
+            Err(WebhookError::ReplayedNonce)
+        ));
+    }
+
+    #[test]
+    fn test_parse_custom_and_malformed() {
+        let body = "fromUserId=u1&toUserId=u2&objectName=App%3AOrder&content=%7B%7D\
+            &channelType=PERSON&msgTimestamp=1&msgUID=M1";
+        let message = RoutedMessage::from_form(body).unwrap();
+        assert_eq!(message.object_name(), "App:Order");
+        assert_eq!(message.source, None);
+
+        assert!(matches!(
+            RoutedMessage::from_form("fromUserId=u1"),
+            Err(WebhookError::MalformedRequest(_))
+        ));
+    }
+}